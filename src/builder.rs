@@ -0,0 +1,328 @@
+use std::collections::HashMap;
+
+use crate::color::Rgb24;
+use crate::error::Result;
+use crate::lock::LockedColors;
+use crate::median_cut::median_cut_histogram;
+use crate::octree::Octree;
+use crate::Method;
+
+/// Accumulated quantizer state.
+#[derive(Debug)]
+enum State {
+    /// Color counts used by histogram median cut.
+    Histogram(HashMap<Rgb24, u64>),
+    /// Octree built incrementally as pixels arrive.
+    Octree(Octree),
+}
+
+/// Builds a palette from pixels supplied incrementally.
+///
+/// Pixels can be pushed in arbitrarily sized chunks, such as image tiles,
+/// rows or video frames, without buffering the whole input. Median cut
+/// accumulates a color histogram and octree quantization adds pixels to
+/// the octree directly.
+///
+#[derive(Debug)]
+pub struct PaletteBuilder {
+    palette_size: usize,
+    pixel_count: u64,
+    /// Number of pushed pixels not assigned to a locked color.
+    kept_count: u64,
+    locked: Option<LockedColors>,
+    state: State,
+}
+
+impl PaletteBuilder {
+    /// Creates a new palette builder for the specified method.
+    pub fn new(method: Method, palette_size: usize) -> Self {
+        let state = match method {
+            Method::MedianCut => State::Histogram(HashMap::new()),
+            Method::Octree => State::Octree(Octree::new()),
+        };
+
+        Self {
+            palette_size,
+            pixel_count: 0,
+            kept_count: 0,
+            locked: None,
+            state,
+        }
+    }
+
+    /// Makes the palette start with locked colors.
+    ///
+    /// Pixels pushed afterwards that are close to a locked color are
+    /// assigned to it instead of being quantized. See [`LockedColors`].
+    ///
+    pub fn with_locked(mut self, locked: LockedColors) -> Self {
+        self.locked = Some(locked);
+        self
+    }
+
+    /// Retrieves the number of pixels pushed so far.
+    pub fn pixel_count(&self) -> u64 {
+        self.pixel_count
+    }
+
+    /// Adds a chunk of pixels.
+    pub fn push(&mut self, colors: &[Rgb24]) {
+        self.push_weighted(colors, 1);
+    }
+
+    /// Adds a chunk of pixels, each counted `weight` times.
+    ///
+    /// Weighting lets inputs of different sizes contribute equally, or
+    /// emphasizes some pixels over others. Pixels with a zero weight
+    /// are ignored.
+    ///
+    pub fn push_weighted(&mut self, colors: &[Rgb24], weight: u64) {
+        if weight == 0 {
+            return;
+        }
+
+        self.pixel_count += colors.len() as u64;
+
+        let filtered;
+        let colors = match &self.locked {
+            Some(locked) => {
+                filtered = locked.filter(colors);
+                &filtered
+            }
+            None => colors,
+        };
+
+        self.kept_count += colors.len() as u64;
+
+        match &mut self.state {
+            State::Histogram(histogram) => {
+                for &color in colors {
+                    *histogram.entry(color).or_insert(0) += weight;
+                }
+            }
+            State::Octree(octree) => octree.build_weighted(colors, weight),
+        }
+    }
+
+    /// Adds pixels with individual weights.
+    ///
+    /// This lets some pixels matter more than others, for instance those
+    /// near the center of an image. Pixels with a zero weight are ignored.
+    ///
+    pub fn push_pixels(&mut self, pixels: &[(Rgb24, u64)]) {
+        let nonzero: Vec<_> = pixels.iter().copied().filter(|(_, w)| *w > 0).collect();
+        self.pixel_count += nonzero.len() as u64;
+
+        let pixels = match &self.locked {
+            Some(locked) => locked.filter_pixels(&nonzero),
+            None => nonzero,
+        };
+
+        self.kept_count += pixels.len() as u64;
+
+        match &mut self.state {
+            State::Histogram(histogram) => {
+                for &(color, weight) in &pixels {
+                    *histogram.entry(color).or_insert(0) += weight;
+                }
+            }
+            State::Octree(octree) => octree.build_histogram(&pixels),
+        }
+    }
+
+    /// Adds pixels row by row.
+    pub fn push_rows<I, R>(&mut self, rows: I)
+    where
+        I: IntoIterator<Item = R>,
+        R: AsRef<[Rgb24]>,
+    {
+        rows.into_iter().for_each(|row| self.push(row.as_ref()));
+    }
+
+    /// Consumes the builder and quantizes the accumulated pixels.
+    ///
+    /// Fails if no pixels were pushed or the palette size is zero, or if
+    /// there are more locked colors than the palette size.
+    ///
+    pub fn finish(mut self) -> Result<Vec<Rgb24>> {
+        // Pixel counts can exceed `usize` on 32-bit targets, so only
+        // emptiness is passed on.
+        crate::validate(usize::from(self.pixel_count > 0), self.palette_size)?;
+
+        match self.locked.take() {
+            Some(locked) => {
+                let rest = usize::try_from(self.kept_count).unwrap_or(usize::MAX);
+                locked.complete(self.palette_size, rest, |size| self.quantize(size))
+            }
+            None => {
+                let palette_size = self.palette_size;
+                self.quantize(palette_size)
+            }
+        }
+    }
+
+    /// Quantizes the accumulated pixels into a palette of `palette_size`.
+    fn quantize(self, palette_size: usize) -> Result<Vec<Rgb24>> {
+        match self.state {
+            State::Histogram(histogram) => {
+                let mut histogram: Vec<_> = histogram.into_iter().collect();
+
+                // Hash map iteration order is unspecified, so fix an order
+                // to keep the result deterministic.
+                histogram.sort_unstable_by_key(|(c, _)| (c.r(), c.g(), c.b()));
+
+                if palette_size >= histogram.len() {
+                    return Ok(histogram.into_iter().map(|(c, _)| c).collect());
+                }
+
+                median_cut_histogram(histogram, palette_size)
+            }
+            State::Octree(mut octree) => Ok(octree.into_palette(palette_size)),
+        }
+    }
+}
+
+impl Extend<Rgb24> for PaletteBuilder {
+    fn extend<T: IntoIterator<Item = Rgb24>>(&mut self, iter: T) {
+        let colors: Vec<_> = iter.into_iter().collect();
+        self.push(&colors);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::octree::octree;
+    use crate::weight::WEIGHT_SCALE;
+    use crate::MedianCutOptions;
+
+    fn colors() -> Vec<Rgb24> {
+        vec![
+            Rgb24::new(0, 0, 0),
+            Rgb24::new(53, 52, 12),
+            Rgb24::new(201, 210, 204),
+            Rgb24::new(55, 51, 13),
+            Rgb24::new(221, 210, 204),
+            Rgb24::new(201, 223, 199),
+            Rgb24::new(201, 102, 204),
+            Rgb24::new(23, 56, 124),
+            Rgb24::new(43, 126, 241),
+            Rgb24::new(24, 16, 123),
+            Rgb24::new(23, 55, 101),
+            Rgb24::new(2, 15, 0),
+            Rgb24::new(2, 102, 150),
+            Rgb24::new(200, 201, 201),
+            Rgb24::new(100, 100, 100),
+            Rgb24::new(0, 0, 200),
+            Rgb24::new(255, 255, 255),
+        ]
+    }
+
+    #[test]
+    fn builder_octree_matches_batch() {
+        let colors = colors();
+
+        let mut builder = PaletteBuilder::new(Method::Octree, 5);
+        builder.push_rows(colors.chunks(4));
+
+        assert_eq!(builder.pixel_count(), colors.len() as u64);
+        assert_eq!(builder.finish().unwrap(), octree(&colors, 5).unwrap());
+    }
+
+    #[test]
+    fn builder_median_cut_chunking() {
+        let colors = colors();
+
+        let mut whole = PaletteBuilder::new(Method::MedianCut, 6);
+        whole.push(&colors);
+
+        let mut chunked = PaletteBuilder::new(Method::MedianCut, 6);
+        chunked.push_rows(colors[..6].chunks(3));
+        chunked.extend(colors[6..].iter().copied());
+
+        let palette = whole.finish().unwrap();
+        assert_eq!(palette.len(), 6);
+        assert_eq!(palette, chunked.finish().unwrap());
+    }
+
+    #[test]
+    fn builder_small_input() {
+        let mut builder = PaletteBuilder::new(Method::MedianCut, 4);
+        builder.extend([Rgb24::new(1, 2, 3), Rgb24::new(1, 2, 3)]);
+
+        assert_eq!(builder.finish().unwrap(), vec![Rgb24::new(1, 2, 3)]);
+    }
+
+    #[test]
+    fn builder_weighted() {
+        let dark = [Rgb24::new(0, 0, 0); 3];
+        let light = [Rgb24::new(100, 100, 100)];
+
+        for method in [Method::MedianCut, Method::Octree] {
+            let mut builder = PaletteBuilder::new(method, 1);
+            builder.push(&dark);
+            builder.push_weighted(&light, 3);
+            builder.push_weighted(&[Rgb24::new(255, 0, 0)], 0);
+
+            assert_eq!(builder.finish().unwrap(), vec![Rgb24::new(50, 50, 50)]);
+        }
+    }
+
+    #[test]
+    fn builder_empty() {
+        let builder = PaletteBuilder::new(Method::Octree, 4);
+        assert!(matches!(builder.finish(), Err(crate::Error::EmptyInput)));
+    }
+
+    #[test]
+    fn builder_locked() {
+        let locked = LockedColors::new(&[Rgb24::new(0, 0, 0)]);
+
+        for method in [Method::MedianCut, Method::Octree] {
+            let mut builder = PaletteBuilder::new(method, 2).with_locked(locked.clone());
+            builder.push(&[Rgb24::new(2, 2, 2), Rgb24::new(200, 100, 50)]);
+            builder.push(&[Rgb24::new(200, 100, 50)]);
+
+            assert_eq!(builder.pixel_count(), 3);
+            assert_eq!(
+                builder.finish().unwrap(),
+                vec![Rgb24::new(0, 0, 0), Rgb24::new(200, 100, 50)]
+            );
+        }
+    }
+
+    #[test]
+    fn builder_pixels() {
+        let red = Rgb24::new(200, 0, 0);
+        let gray = Rgb24::new(100, 100, 100);
+
+        // One heavily weighted red pixel pulls the average toward red.
+        let mut builder = PaletteBuilder::new(Method::MedianCut, 1);
+        builder.push_pixels(&[(gray, 1), (gray, 1), (red, 8), (gray, 0)]);
+        assert_eq!(builder.pixel_count(), 3);
+        assert_eq!(builder.finish().unwrap(), vec![Rgb24::new(180, 20, 20)]);
+
+        let mut builder = PaletteBuilder::new(Method::Octree, 2);
+        builder.push_pixels(&[(gray, 3), (red, 1)]);
+        assert_eq!(builder.finish().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn builder_uniform_weights() {
+        let colors = colors();
+        let uniform: Vec<_> = colors.iter().map(|&c| (c, WEIGHT_SCALE)).collect();
+
+        // Uniform weights give the same palette as histogram median cut.
+        let mut builder = PaletteBuilder::new(Method::MedianCut, 5);
+        builder.push_pixels(&uniform);
+        let options = MedianCutOptions { histogram: true };
+        assert_eq!(
+            builder.finish().unwrap(),
+            crate::solve(&options, colors.clone(), 5).unwrap()
+        );
+
+        let mut builder = PaletteBuilder::new(Method::Octree, 5);
+        builder.push_pixels(&uniform);
+        assert_eq!(builder.finish().unwrap(), octree(&colors, 5).unwrap());
+    }
+}
@@ -0,0 +1,354 @@
+mod compare;
+mod contrast;
+mod convert;
+mod extract;
+mod files;
+mod inspect;
+mod remap;
+mod report;
+mod term;
+mod theme;
+
+use std::error::Error;
+use std::fs;
+use std::io::{self, Cursor, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::{Args, Parser, Subcommand};
+
+use paletter::color::Rgb24;
+use paletter::PaletteFormat;
+
+use term::{ColorMode, Term};
+
+/// Result type of the command-line interface.
+type CliResult<T = ()> = Result<T, Box<dyn Error>>;
+
+/// A super simple command-line palette quantizer.
+#[derive(Parser, Debug)]
+#[command(
+    author,
+    version,
+    about,
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
+pub struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// When to color the output.
+    #[clap(long, global = true, value_enum, default_value_t)]
+    color: ColorMode,
+
+    /// Palette extraction, used when no subcommand is given.
+    #[command(flatten)]
+    extract: extract::ExtractArgs,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Generate a palette for each image. This is the default command.
+    Extract(Box<extract::ExtractArgs>),
+    /// Map the pixels of an image onto a palette.
+    Remap(remap::RemapArgs),
+    /// Convert a palette file to another format.
+    Convert(convert::ConvertArgs),
+    /// Compare two palettes or two images.
+    Compare(compare::CompareArgs),
+    /// Show color statistics of images.
+    Inspect(inspect::InspectArgs),
+    /// Check the contrast between the colors of a palette.
+    Contrast(contrast::ContrastArgs),
+    /// Generate a terminal color scheme from an image.
+    Theme(theme::ThemeArgs),
+}
+
+impl Cli {
+    /// Runs the selected command and reports any error.
+    pub fn run(self) -> ExitCode {
+        let mut term = Term::new(self.color);
+
+        let result = match self.command {
+            None => extract::run(self.extract, &mut term),
+            Some(Command::Extract(args)) => extract::run(*args, &mut term),
+            Some(Command::Remap(args)) => remap::run(args).map(|_| Status::Success),
+            Some(Command::Convert(args)) => convert::run(args).map(|_| Status::Success),
+            Some(Command::Compare(args)) => compare::run(args, &mut term).map(|_| Status::Success),
+            Some(Command::Inspect(args)) => inspect::run(args, &mut term),
+            Some(Command::Contrast(args)) => {
+                contrast::run(args, &mut term).map(|_| Status::Success)
+            }
+            Some(Command::Theme(args)) => theme::run(args, &mut term).map(|_| Status::Success),
+        };
+
+        match result {
+            Ok(status) => status.into(),
+            Err(err) => {
+                let _ = term.error(&format!("error: {err}"));
+                Status::from_dyn_error(err.as_ref()).into()
+            }
+        }
+    }
+}
+
+/// Exit status of a command.
+///
+/// When several inputs fail, the status of the first failure is used.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    /// Every input was processed.
+    Success = 0,
+    /// Unexpected failure, such as an output that cannot be written.
+    Failure = 1,
+    /// An input file does not exist.
+    NotFound = 3,
+    /// An input file cannot be read due to its permissions.
+    PermissionDenied = 4,
+    /// An input file is corrupt or cannot be parsed.
+    InvalidData = 5,
+    /// An input file is in an unsupported format.
+    Unsupported = 6,
+    /// An input has no colors to quantize or the palette size is invalid.
+    NoPalette = 7,
+}
+
+impl Status {
+    /// Classifies a library error.
+    fn from_error(err: &paletter::Error) -> Self {
+        match err {
+            paletter::Error::Io(err) => Self::from_io_error(err),
+            paletter::Error::Decode(_)
+            | paletter::Error::InvalidBuffer(_)
+            | paletter::Error::InvalidColor(_)
+            | paletter::Error::InvalidPalette(_) => Status::InvalidData,
+            paletter::Error::UnsupportedFormat(_) => Status::Unsupported,
+            paletter::Error::EmptyInput | paletter::Error::InvalidSize(_) => Status::NoPalette,
+            _ => Status::Failure,
+        }
+    }
+
+    /// Classifies an I/O error.
+    fn from_io_error(err: &io::Error) -> Self {
+        match err.kind() {
+            io::ErrorKind::NotFound => Status::NotFound,
+            io::ErrorKind::PermissionDenied => Status::PermissionDenied,
+            io::ErrorKind::InvalidData => Status::InvalidData,
+            _ => Status::Failure,
+        }
+    }
+
+    /// Classifies any error raised by a command.
+    fn from_dyn_error(err: &(dyn Error + 'static)) -> Self {
+        if let Some(err) = err.downcast_ref::<paletter::Error>() {
+            Self::from_error(err)
+        } else if let Some(err) = err.downcast_ref::<io::Error>() {
+            Self::from_io_error(err)
+        } else {
+            Status::Failure
+        }
+    }
+}
+
+impl From<Status> for ExitCode {
+    fn from(status: Status) -> Self {
+        ExitCode::from(status as u8)
+    }
+}
+
+/// Options controlling how colors are displayed.
+#[derive(Args, Debug)]
+struct DisplayArgs {
+    /// Display the colors in hexadecimal.
+    #[clap(long)]
+    hex: bool,
+
+    /// Display the colors in RGB24.
+    #[clap(long)]
+    rgb: bool,
+
+    /// Display colors without any color styling.
+    #[clap(long, short, conflicts_with_all = ["swatch", "grid"])]
+    uncolored: bool,
+
+    /// Display colors as solid blocks with contrasting text.
+    #[clap(long, short = 'w')]
+    swatch: bool,
+
+    /// Display swatches in a grid with the given number of columns.
+    #[clap(long, value_name = "COLUMNS")]
+    grid: Option<usize>,
+}
+
+impl DisplayArgs {
+    /// Checks whether colors are displayed as swatches.
+    fn is_swatch(&self) -> bool {
+        self.swatch || self.grid.is_some()
+    }
+}
+
+/// Path used to read from stdin or write to stdout.
+const STD_PATH: &str = "-";
+
+/// Name shown for an input or output path.
+fn display_name(path: &str) -> &str {
+    if path == STD_PATH {
+        "<stdin>"
+    } else {
+        path
+    }
+}
+
+/// Reads a whole file, or stdin if the path is `-`.
+fn read_input(path: &str) -> io::Result<Vec<u8>> {
+    if path == STD_PATH {
+        let mut bytes = Vec::new();
+        io::stdin().lock().read_to_end(&mut bytes)?;
+        Ok(bytes)
+    } else {
+        fs::read(path)
+    }
+}
+
+/// Writes a whole file, or stdout if the path is `-`.
+fn write_output(path: &Path, bytes: &[u8]) -> io::Result<()> {
+    if path == Path::new(STD_PATH) {
+        let mut stdout = io::stdout().lock();
+        stdout.write_all(bytes)?;
+        stdout.flush()
+    } else {
+        fs::write(path, bytes)
+    }
+}
+
+/// Opens an image file, or stdin if the path is `-`.
+fn open_image(path: &str) -> paletter::Result<image::DynamicImage> {
+    if path == STD_PATH {
+        Ok(image::load_from_memory(&read_input(path)?)?)
+    } else {
+        Ok(image::open(path)?)
+    }
+}
+
+/// Reads a palette file, guessing the format from the extension if not given.
+fn read_palette(path: &str, format: Option<PaletteFormat>) -> CliResult<Vec<Rgb24>> {
+    let format = palette_format(Path::new(path), format)?;
+    let bytes = read_input(path).map_err(paletter::Error::from)?;
+    let text = String::from_utf8(bytes)?;
+    Ok(format.parse(&text)?)
+}
+
+/// Resolves the palette format of a path.
+fn palette_format(path: &Path, format: Option<PaletteFormat>) -> CliResult<PaletteFormat> {
+    format
+        .or_else(|| PaletteFormat::from_path(path))
+        .ok_or_else(|| format!("unknown palette format: {}", path.display()).into())
+}
+
+/// Guesses an image format from an explicit extension or the output path.
+/// PNG is used when writing to stdout.
+fn image_format(path: &Path, ext: Option<&str>) -> CliResult<image::ImageFormat> {
+    let format = match ext {
+        Some(ext) => image::ImageFormat::from_extension(ext),
+        None if path == Path::new(STD_PATH) => Some(image::ImageFormat::Png),
+        None => image::ImageFormat::from_path(path).ok(),
+    };
+
+    Ok(format.ok_or_else(|| format!("unknown image format: {}", path.display()))?)
+}
+
+/// Encodes an image, dropping the alpha channel for formats without one.
+fn encode_image(img: image::RgbaImage, format: image::ImageFormat) -> CliResult<Vec<u8>> {
+    let img = match format {
+        image::ImageFormat::Jpeg | image::ImageFormat::Pnm => {
+            image::DynamicImage::ImageRgb8(image::DynamicImage::ImageRgba8(img).to_rgb8())
+        }
+        _ => image::DynamicImage::ImageRgba8(img),
+    };
+
+    let mut bytes = Cursor::new(Vec::new());
+    img.write_to(&mut bytes, format)?;
+    Ok(bytes.into_inner())
+}
+
+/// Derives the output path for the `index`-th of `count` inputs.
+///
+/// With a single input, the path is used as is. Otherwise the input
+/// number is appended to the file stem, e.g. `palette-2.gpl`.
+///
+fn indexed_path(path: &Path, index: usize, count: usize) -> PathBuf {
+    if count <= 1 || path == Path::new(STD_PATH) {
+        return path.to_path_buf();
+    }
+
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let name = match path.extension() {
+        Some(ext) => format!("{stem}-{}.{}", index + 1, ext.to_string_lossy()),
+        None => format!("{stem}-{}", index + 1),
+    };
+
+    path.with_file_name(name)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn cli_status_from_error() {
+        let io = |kind| paletter::Error::Io(io::Error::from(kind));
+        assert_eq!(
+            Status::from_error(&io(io::ErrorKind::NotFound)),
+            Status::NotFound
+        );
+        assert_eq!(
+            Status::from_error(&io(io::ErrorKind::PermissionDenied)),
+            Status::PermissionDenied
+        );
+        assert_eq!(
+            Status::from_error(&io(io::ErrorKind::Other)),
+            Status::Failure
+        );
+
+        let decode = image::ImageError::Decoding(image::error::DecodingError::new(
+            image::error::ImageFormatHint::Unknown,
+            "corrupt",
+        ));
+        assert_eq!(
+            Status::from_error(&paletter::Error::from(decode)),
+            Status::InvalidData
+        );
+        assert_eq!(
+            Status::from_error(&paletter::Error::InvalidPalette("empty".into())),
+            Status::InvalidData
+        );
+        assert_eq!(
+            Status::from_error(&paletter::Error::UnsupportedFormat("qoi".into())),
+            Status::Unsupported
+        );
+        assert_eq!(
+            Status::from_error(&paletter::Error::EmptyInput),
+            Status::NoPalette
+        );
+        assert_eq!(
+            Status::from_error(&paletter::Error::InvalidSize(0)),
+            Status::NoPalette
+        );
+    }
+
+    #[test]
+    fn cli_status_from_dyn_error() {
+        let err: Box<dyn Error> = Box::new(paletter::Error::EmptyInput);
+        assert_eq!(Status::from_dyn_error(err.as_ref()), Status::NoPalette);
+
+        let err: Box<dyn Error> = Box::new(io::Error::from(io::ErrorKind::InvalidData));
+        assert_eq!(Status::from_dyn_error(err.as_ref()), Status::InvalidData);
+
+        let err: Box<dyn Error> = "cannot lock 3 colors in a palette of 2".into();
+        assert_eq!(Status::from_dyn_error(err.as_ref()), Status::Failure);
+
+        assert_eq!(ExitCode::from(Status::NotFound), ExitCode::from(3));
+    }
+}
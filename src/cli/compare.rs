@@ -0,0 +1,108 @@
+use std::io::Write;
+
+use clap::Args;
+
+use paletter::color::Rgb24;
+use paletter::{metrics, PaletteFormat};
+
+use super::{display_name, open_image, read_palette, CliResult, DisplayArgs, Term};
+
+#[derive(Args, Debug)]
+pub struct CompareArgs {
+    /// First palette file or image.
+    first: String,
+
+    /// Second palette file or image.
+    second: String,
+
+    #[command(flatten)]
+    display: DisplayArgs,
+}
+
+/// Compares two palettes or two images.
+pub fn run(args: CompareArgs, term: &mut Term) -> CliResult {
+    let is_palette = |path: &str| PaletteFormat::from_path(path).is_some();
+
+    match (is_palette(&args.first), is_palette(&args.second)) {
+        (true, true) => compare_palettes(&args, term),
+        (false, false) => compare_images(&args, term),
+        _ => Err("cannot compare a palette with an image".into()),
+    }
+}
+
+/// Matches every color of each palette with its closest color in the other.
+fn compare_palettes(args: &CompareArgs, term: &mut Term) -> CliResult {
+    let first = read_palette(&args.first, None)?;
+    let second = read_palette(&args.second, None)?;
+
+    for (i, (path, from, to)) in [
+        (&args.first, &first, &second),
+        (&args.second, &second, &first),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        term.heading(&format!("Palette {}", i + 1), path)?;
+
+        let labs: Vec<_> = to.iter().map(Rgb24::to_lab).collect();
+
+        for color in from {
+            let lab = color.to_lab();
+            let Some((nearest, delta)) = to
+                .iter()
+                .zip(&labs)
+                .map(|(c, other)| (c, lab.delta_e(other)))
+                .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            else {
+                break;
+            };
+
+            term.color(color, &args.display)?;
+            write!(term.stdout, "  ->  ")?;
+            term.color(nearest, &args.display)?;
+            writeln!(term.stdout, "  \u{394}E {delta:.2}")?;
+        }
+
+        let distance = metrics::palette_distance(from, to);
+        writeln!(term.stdout, "Mean \u{394}E: {distance:.2}")?;
+        writeln!(term.stdout)?;
+    }
+
+    Ok(())
+}
+
+/// Measures the pixel difference between two images of the same size.
+fn compare_images(args: &CompareArgs, term: &mut Term) -> CliResult {
+    let first = open_image(&args.first)?.to_rgba8();
+    let second = open_image(&args.second)?.to_rgba8();
+
+    if first.dimensions() != second.dimensions() {
+        return Err(format!(
+            "image sizes differ: {}x{} and {}x{}",
+            first.width(),
+            first.height(),
+            second.width(),
+            second.height()
+        )
+        .into());
+    }
+
+    let a = paletter::rgba_image_to_rgb24(&first, u8::MIN);
+    let b = paletter::rgba_image_to_rgb24(&second, u8::MIN);
+
+    term.heading("Image 1", display_name(&args.first))?;
+    term.heading("Image 2", display_name(&args.second))?;
+
+    let differing = a.iter().zip(&b).filter(|(x, y)| x != y).count();
+
+    writeln!(term.stdout, "Differing pixels: {differing} of {}", a.len())?;
+    writeln!(term.stdout, "MSE: {:.3}", metrics::mse(&a, &b))?;
+    writeln!(term.stdout, "PSNR: {:.2} dB", metrics::psnr(&a, &b))?;
+    writeln!(
+        term.stdout,
+        "Mean \u{394}E: {:.3}",
+        metrics::mean_delta_e(&a, &b)
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use std::io::Write;
+
+use clap::Args;
+use termcolor::WriteColor;
+
+use paletter::contrast::{self, Level};
+use paletter::PaletteFormat;
+
+use super::{display_name, read_palette, CliResult, DisplayArgs, Term};
+
+#[derive(Args, Debug)]
+pub struct ContrastArgs {
+    /// Palette file. Use `-` with `--format` to read from stdin.
+    palette: String,
+
+    /// Palette file format. Guessed from the extension by default.
+    #[clap(long)]
+    format: Option<PaletteFormat>,
+
+    /// Only list the pairs that pass a WCAG level.
+    #[clap(long, value_name = "LEVEL")]
+    min_level: Option<Level>,
+
+    #[command(flatten)]
+    display: DisplayArgs,
+}
+
+/// Lists the contrast of every text and background pair of a palette.
+pub fn run(args: ContrastArgs, term: &mut Term) -> CliResult {
+    let palette = read_palette(&args.palette, args.format)?;
+
+    let pairs = contrast::pairs(&palette);
+
+    term.heading("Palette", display_name(&args.palette))?;
+
+    let listed = pairs
+        .iter()
+        .filter(|pair| args.min_level.is_none_or(|level| pair.passes(level)));
+
+    for pair in listed {
+        let (text, background) = (&palette[pair.text], &palette[pair.background]);
+
+        term.color(text, &args.display)?;
+        write!(term.stdout, " on ")?;
+        term.color(background, &args.display)?;
+        write!(term.stdout, "  ")?;
+        // Samples need colors to show anything.
+        if !args.display.uncolored && term.stdout.supports_color() {
+            term.sample("Aa", text, background)?;
+            write!(term.stdout, "  ")?;
+        }
+
+        write!(term.stdout, "{:>5.2}:1  Lc {:>6.1}", pair.ratio, pair.lc)?;
+        writeln!(term.stdout, "  {}", flags(pair.ratio))?;
+    }
+
+    writeln!(term.stdout)?;
+    for level in Level::ALL {
+        let passing = pairs.iter().filter(|pair| pair.passes(level)).count();
+        term.note(&format!(
+            "Passing {}: {passing} of {} pairs",
+            level_name(level),
+            pairs.len()
+        ))?;
+    }
+
+    Ok(())
+}
+
+/// Lists the WCAG criteria met by a contrast ratio, for normal and large text.
+fn flags(ratio: f32) -> String {
+    let criteria = [
+        ("AA", Level::Aa),
+        ("AA large", Level::AaLarge),
+        ("AAA", Level::Aaa),
+        ("AAA large", Level::Aa),
+    ];
+
+    let passed: Vec<_> = criteria
+        .into_iter()
+        .filter(|(_, level)| ratio >= level.min_ratio())
+        .map(|(name, _)| name)
+        .collect();
+
+    match passed.is_empty() {
+        true => "fail".to_string(),
+        false => passed.join(", "),
+    }
+}
+
+/// Gets the display name of a WCAG level.
+fn level_name(level: Level) -> &'static str {
+    match level {
+        Level::AaLarge => "AA large",
+        Level::Aa => "AA",
+        Level::Aaa => "AAA",
+    }
+}
@@ -0,0 +1,34 @@
+use std::path::PathBuf;
+
+use clap::Args;
+
+use paletter::PaletteFormat;
+
+use super::{palette_format, read_palette, write_output, CliResult};
+
+#[derive(Args, Debug)]
+pub struct ConvertArgs {
+    /// Palette file path. Use `-` to read from stdin.
+    input: String,
+
+    /// Output palette path. Use `-` to write to stdout.
+    output: PathBuf,
+
+    /// Input palette format. Guessed from the input extension by default.
+    #[clap(long)]
+    from: Option<PaletteFormat>,
+
+    /// Output palette format. Guessed from the output extension by default.
+    #[clap(long)]
+    to: Option<PaletteFormat>,
+}
+
+/// Converts a palette file to another format.
+pub fn run(args: ConvertArgs) -> CliResult {
+    let palette = read_palette(&args.input, args.from)?;
+    let format = palette_format(&args.output, args.to)?;
+
+    write_output(&args.output, format.write(&palette).as_bytes())?;
+
+    Ok(())
+}
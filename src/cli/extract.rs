@@ -0,0 +1,789 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use clap::Args;
+use image::{imageops, DynamicImage, GrayImage};
+use rayon::prelude::*;
+
+use paletter::color::Rgb24;
+use paletter::cvd::{self, Deficiency};
+use paletter::{filter, remap, sort, swatch, Method, PaletteBuilder, PaletteFormat, Quantizer};
+use paletter::{weight, ColorNames, Exclusion, LockedColors, NameSet, SortOrder};
+use paletter::{SwatchLayout, SwatchOptions, Weighting};
+
+use super::files;
+use super::report::{self, Entry};
+use super::{display_name, encode_image, image_format, indexed_path, open_image, read_input};
+use super::{palette_format, write_output};
+use super::{CliResult, DisplayArgs, Status, Term, STD_PATH};
+
+#[derive(Args, Debug)]
+pub struct ExtractArgs {
+    /// Number of colors in the palette.
+    #[clap(required = true)]
+    palette_size: Option<usize>,
+
+    /// List of image file paths, directories or glob patterns. A palette will
+    /// be generated for each image. Directories are searched recursively.
+    /// Use `-` to read an image from stdin.
+    #[arg(required = true, num_args = 1..)]
+    files: Vec<String>,
+
+    /// Quantization method.
+    #[clap(long)]
+    method: Option<Method>,
+
+    #[command(flatten)]
+    display: DisplayArgs,
+
+    /// Alpha channel threshold.
+    #[clap(long, short)]
+    alpha_thresh: Option<u8>,
+
+    /// Sort by HSV. Shorthand for `--sort-by hsv`.
+    #[clap(long, short, conflicts_with = "sort_by")]
+    sort: bool,
+
+    /// Palette order.
+    #[clap(long, value_name = "ORDER")]
+    sort_by: Option<SortOrder>,
+
+    /// Reverse the palette order.
+    #[clap(long)]
+    reverse: bool,
+
+    /// Show how many pixels map to each palette color as a bar.
+    #[clap(long, conflicts_with = "grid")]
+    bars: bool,
+
+    /// Only use the pixels in a rectangle given as `x,y,width,height`.
+    #[clap(long, value_name = "X,Y,W,H")]
+    crop: Option<Crop>,
+
+    /// Only use the pixels that are white in a mask image of the same size
+    /// as the input.
+    #[clap(long, value_name = "PATH")]
+    mask: Option<String>,
+
+    /// Give some pixels more influence on the palette, so that small
+    /// subjects are not averaged away. Weighted pixels are quantized with
+    /// histogram median cut, which splits buckets at the weighted median.
+    #[clap(long, value_name = "WEIGHTING", conflicts_with = "weight_map")]
+    weight: Option<Weighting>,
+
+    /// Weigh pixels by a grayscale image of the same size as the input,
+    /// from ignored (black) to full weight (white). Uses histogram median
+    /// cut like `--weight`.
+    #[clap(long, value_name = "PATH")]
+    weight_map: Option<String>,
+
+    /// Colors to leave out of the palette, with an optional Delta E
+    /// tolerance such as `#FFFFFF~10`, separated by commas.
+    #[clap(long, value_name = "COLORS", value_delimiter = ',')]
+    exclude: Vec<Exclusion>,
+
+    /// Leave out a solid background detected from the image border.
+    #[clap(long)]
+    exclude_corners: bool,
+
+    /// Colors that must appear in the palette, separated by commas.
+    #[clap(long, value_name = "COLORS", value_delimiter = ',')]
+    lock: Vec<Rgb24>,
+
+    /// Distance (Delta E) within which pixels are assigned to a locked color.
+    #[clap(long, value_name = "DELTA_E", requires = "lock")]
+    lock_tolerance: Option<f32>,
+
+    /// Generate a single palette from the pixels of all images.
+    #[clap(long, short)]
+    combine: bool,
+
+    /// Weight each image equally when combining, regardless of resolution.
+    #[clap(long, requires = "combine")]
+    equal_weight: bool,
+
+    /// Show the closest color name and its Delta E next to each color.
+    #[clap(long, conflicts_with_all = ["grid", "bars"])]
+    names: bool,
+
+    /// Built-in color names.
+    #[clap(long, value_name = "SET", requires = "names", default_value = "css")]
+    name_set: NameSet,
+
+    /// Files of extra color names, with a color and its name on each line
+    /// such as `#E4572E Flame`.
+    #[clap(long, value_name = "PATH", requires = "names")]
+    name_list: Vec<String>,
+
+    /// Show each palette as seen with color vision deficiencies, separated
+    /// by commas, and warn about colors that become indistinguishable.
+    #[clap(long, value_name = "DEFICIENCIES", value_delimiter = ',')]
+    simulate: Vec<Deficiency>,
+
+    /// Distance (Delta E) below which simulated colors are indistinguishable.
+    #[clap(long, value_name = "DELTA_E", requires = "simulate")]
+    cvd_threshold: Option<f32>,
+
+    /// Write each palette to a file. The input number is appended to the
+    /// file name when there are several palettes. Use `-` for stdout.
+    #[clap(long, short)]
+    output: Option<PathBuf>,
+
+    /// Palette file format. Guessed from the output extension by default.
+    #[clap(long, requires = "output")]
+    format: Option<PaletteFormat>,
+
+    /// Render each palette as an SVG or raster image. The input number is
+    /// appended to the file name when there are several palettes.
+    #[clap(long, value_name = "PATH")]
+    swatch_out: Option<PathBuf>,
+
+    /// Arrangement of the colors in the swatch image.
+    #[clap(
+        long,
+        value_name = "LAYOUT",
+        requires = "swatch_out",
+        default_value = "strip"
+    )]
+    swatch_layout: SwatchLayout,
+
+    /// Number of cells per row in the swatch grid layout.
+    #[clap(
+        long,
+        value_name = "COLUMNS",
+        requires = "swatch_out",
+        default_value_t = 8
+    )]
+    swatch_columns: usize,
+
+    /// Write hex codes on the swatch image.
+    #[clap(long, requires = "swatch_out")]
+    labels: bool,
+
+    /// Write an HTML report comparing the methods on each image.
+    #[clap(long, value_name = "PATH")]
+    report: Option<PathBuf>,
+
+    /// Stop at the first input that cannot be processed.
+    #[clap(long)]
+    fail_fast: bool,
+}
+
+/// Rectangular region of an image.
+#[derive(Debug, Clone, Copy)]
+struct Crop {
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+}
+
+impl Crop {
+    /// Crops an image and its mask. The region is clipped to the image.
+    fn apply(
+        self,
+        img: &DynamicImage,
+        mask: Option<&GrayImage>,
+    ) -> paletter::Result<(DynamicImage, Option<GrayImage>)> {
+        if self.x >= img.width() || self.y >= img.height() {
+            return Err(paletter::Error::InvalidBuffer(format!(
+                "crop origin {},{} is outside the {}x{} image",
+                self.x,
+                self.y,
+                img.width(),
+                img.height()
+            )));
+        }
+
+        let Crop {
+            x,
+            y,
+            width,
+            height,
+        } = self;
+        let mask = mask.map(|mask| self.apply_gray(mask));
+
+        Ok((img.crop_imm(x, y, width, height), mask))
+    }
+
+    /// Crops a grayscale image such as a weight map.
+    fn apply_gray(self, img: &GrayImage) -> GrayImage {
+        imageops::crop_imm(img, self.x, self.y, self.width, self.height).to_image()
+    }
+}
+
+impl FromStr for Crop {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let values = s
+            .split(',')
+            .map(|v| v.trim().parse::<u32>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| err.to_string())?;
+
+        match values[..] {
+            [x, y, width, height] if width > 0 && height > 0 => Ok(Self {
+                x,
+                y,
+                width,
+                height,
+            }),
+            [_, _, _, _] => Err("width and height must be positive".into()),
+            _ => Err("expected x,y,width,height".into()),
+        }
+    }
+}
+
+impl ExtractArgs {
+    /// Gets the requested palette order, if any.
+    fn sort_order(&self) -> Option<SortOrder> {
+        self.sort_by.or(self.sort.then_some(SortOrder::Hsv))
+    }
+}
+
+/// Generates a palette for each input image, or one for all of them.
+pub fn run(args: ExtractArgs, term: &mut Term) -> CliResult<Status> {
+    let palette_size = args.palette_size.unwrap_or_default();
+    let method = args.method.unwrap_or(Method::MedianCut);
+    let alpha_min = args.alpha_thresh.unwrap_or(0);
+
+    let mask = match &args.mask {
+        Some(path) => Some(open_image(path)?.to_luma8()),
+        None => None,
+    };
+
+    let weight_map = match &args.weight_map {
+        Some(path) => Some(open_image(path)?.to_luma8()),
+        None => None,
+    };
+
+    let names = match args.names {
+        true => {
+            let mut names = ColorNames::builtin(args.name_set);
+            for path in &args.name_list {
+                let bytes = read_input(path).map_err(paletter::Error::from)?;
+                names.extend(&ColorNames::parse(&String::from_utf8(bytes)?)?);
+            }
+            Some(names)
+        }
+        false => None,
+    };
+
+    let locked = match args.lock.as_slice() {
+        [] => None,
+        lock => {
+            let locked = LockedColors::new(lock);
+            Some(match args.lock_tolerance {
+                Some(tolerance) => locked.with_tolerance(tolerance),
+                None => locked,
+            })
+        }
+    };
+
+    if let Some(locked) = locked.as_ref().filter(|locked| locked.len() > palette_size) {
+        return Err(format!(
+            "cannot lock {} colors in a palette of {palette_size}",
+            locked.len()
+        )
+        .into());
+    }
+
+    let solve = |method: Method, pixels: &Pixels| match &pixels.weighted {
+        Some(weighted) => {
+            let mut builder = PaletteBuilder::new(method, palette_size);
+            if let Some(locked) = &locked {
+                builder = builder.with_locked(locked.clone());
+            }
+            builder.push_pixels(weighted);
+            builder.finish()
+        }
+        None => match &locked {
+            Some(locked) => locked.solve(&method, pixels.colors.clone(), palette_size),
+            None => paletter::solve(&method, pixels.colors.clone(), palette_size),
+        },
+    };
+
+    // Palettes written to stdout replace the listing.
+    let to_stdout = args
+        .output
+        .as_ref()
+        .is_some_and(|output| output == Path::new(STD_PATH));
+
+    let format = match &args.output {
+        Some(_) if to_stdout => Some(args.format.unwrap_or(PaletteFormat::Hex)),
+        Some(output) => Some(palette_format(output, args.format)?),
+        None => None,
+    };
+
+    let swatch_format = match &args.swatch_out {
+        Some(path) if is_svg(path) => None,
+        Some(path) => Some(image_format(path, None)?),
+        None => None,
+    };
+
+    let outputs = |i, count| Outputs {
+        palette: args
+            .output
+            .as_ref()
+            .zip(format)
+            .map(|(path, format)| (indexed_path(path, i, count), format)),
+        swatch: args
+            .swatch_out
+            .as_ref()
+            .map(|path| (indexed_path(path, i, count), swatch_format)),
+    };
+
+    // Pixel counts are needed to size population bars and sort by frequency.
+    let counted = args.bars
+        || args.swatch_out.is_some() && args.swatch_layout == SwatchLayout::Bars
+        || args.sort_order() == Some(SortOrder::Frequency);
+
+    let (files, mut skipped) = files::expand(&args.files);
+    let total = files.len() + skipped.len();
+
+    if args.fail_fast && !skipped.is_empty() {
+        return files::report(term, &skipped, total);
+    }
+
+    // Once an input fails in fail-fast mode, the remaining inputs are cancelled.
+    let failed = AtomicBool::new(false);
+
+    let process = |path: &String, quantize: bool| {
+        if args.fail_fast && failed.load(Ordering::Relaxed) {
+            return None;
+        }
+
+        let result = open_image(path).and_then(|img| {
+            let (img, mask) = match args.crop {
+                Some(crop) => {
+                    let (img, mask) = crop.apply(&img, mask.as_ref())?;
+                    (img, mask.map(Cow::Owned))
+                }
+                None => (img, mask.as_ref().map(Cow::Borrowed)),
+            };
+            let weights = match (args.weight, &weight_map) {
+                (Some(weighting), _) => {
+                    let pixels = paletter::rgb_image_to_rgb24(&img.to_rgb8());
+                    let (width, height) = (img.width() as usize, img.height() as usize);
+                    Some(weighting.weights(&pixels, width, height))
+                }
+                (None, Some(map)) => {
+                    let map = match args.crop {
+                        Some(crop) => Cow::Owned(crop.apply_gray(map)),
+                        None => Cow::Borrowed(map),
+                    };
+                    if map.dimensions() != (img.width(), img.height()) {
+                        return Err(paletter::Error::InvalidBuffer(format!(
+                            "weight map is {}x{} but the image is {}x{}",
+                            map.width(),
+                            map.height(),
+                            img.width(),
+                            img.height()
+                        )));
+                    }
+                    Some(weight::map_weights(map.as_raw()))
+                }
+                (None, None) => None,
+            };
+
+            let mut exclusions = args.exclude.clone();
+            if args.exclude_corners {
+                exclusions.extend(
+                    background(&img)
+                        .map(|color| Exclusion::new(color, Exclusion::DEFAULT_TOLERANCE)),
+                );
+            }
+
+            let pixels = match weights {
+                Some(weights) => {
+                    let weighted = paletter::weighted_image_to_rgb24(
+                        &img,
+                        alpha_min,
+                        mask.as_deref(),
+                        &weights,
+                    )?;
+                    let weighted = filter::exclude_pixels(&weighted, &exclusions);
+                    Pixels {
+                        colors: weighted.iter().map(|(color, _)| *color).collect(),
+                        weighted: Some(weighted),
+                    }
+                }
+                None => {
+                    let colors = paletter::masked_image_to_rgb24(&img, alpha_min, mask.as_deref())?;
+                    Pixels {
+                        colors: filter::exclude(&colors, &exclusions),
+                        weighted: None,
+                    }
+                }
+            };
+
+            let palette = match quantize {
+                true => Some(solve(method, &pixels)?),
+                false => None,
+            };
+
+            let entry = match args.report {
+                Some(_) => {
+                    let name = display_name(path);
+                    let (order, reverse) = (args.sort_order(), args.reverse);
+                    // The palette of the selected method is reused.
+                    let solve = |m| match &palette {
+                        Some(palette) if m == method => Ok(palette.clone()),
+                        _ => solve(m, &pixels),
+                    };
+                    Some(Entry::new(
+                        name,
+                        &img,
+                        &pixels.colors,
+                        method,
+                        &solve,
+                        order,
+                        reverse,
+                    )?)
+                }
+                None => None,
+            };
+
+            let Some(palette) = palette else {
+                return Ok((Processed::Pixels(pixels), entry));
+            };
+
+            let population = match counted {
+                true => remap::population(&pixels.colors, &palette),
+                false => vec![],
+            };
+            Ok((Processed::Palette(palette, population), entry))
+        });
+
+        if result.is_err() {
+            failed.store(true, Ordering::Relaxed);
+        }
+
+        Some(result)
+    };
+
+    // Combined pixels are pushed into a builder as images are decoded, and
+    // then dropped.
+    let mut builder = args.combine.then(|| {
+        let builder = PaletteBuilder::new(method, palette_size);
+        match &locked {
+            Some(locked) => builder.with_locked(locked.clone()),
+            None => builder,
+        }
+    });
+    let mut histogram = HashMap::new();
+
+    // Images are decoded and quantized in parallel, then reported in order.
+    // Combined images are decoded a batch at a time to bound memory use.
+    let batch = match builder {
+        Some(_) => rayon::current_num_threads(),
+        None => files.len(),
+    };
+
+    let mut combined = 0;
+    let mut entries = Vec::new();
+
+    'batches: for (b, chunk) in files.chunks(batch.max(1)).enumerate() {
+        let results: Vec<_> = chunk
+            .par_iter()
+            .map(|path| process(path, !args.combine))
+            .collect();
+
+        for (j, (path, result)) in chunk.iter().zip(results).enumerate() {
+            let (processed, entry) = match result {
+                Some(Ok(result)) => result,
+                Some(Err(err)) => {
+                    files::skip_error(&mut skipped, display_name(path), &err);
+                    if args.fail_fast {
+                        break 'batches;
+                    }
+                    continue;
+                }
+                None => continue,
+            };
+
+            entries.extend(entry);
+
+            match processed {
+                Processed::Pixels(pixels) => {
+                    if let Some(builder) = &mut builder {
+                        push_image(builder, &pixels, args.equal_weight);
+                    }
+                    if counted {
+                        for &color in &pixels.colors {
+                            *histogram.entry(color).or_insert(0) += 1;
+                        }
+                    }
+                    combined += 1;
+                }
+                Processed::Palette(palette, population) => {
+                    let i = b * batch + j;
+                    let title = format!("Image {}", i + 1);
+                    let name = display_name(path);
+                    let outputs = outputs(i, files.len());
+                    let palette = (palette, population);
+                    emit(term, &args, &title, name, palette, outputs, names.as_ref())?;
+                }
+            }
+        }
+    }
+
+    let stopped = args.fail_fast && !skipped.is_empty();
+
+    if let Some(builder) = builder.filter(|_| combined > 0 && !stopped) {
+        match builder.finish() {
+            Ok(palette) => {
+                let name = match combined {
+                    1 => "1 image".to_string(),
+                    n => format!("{n} images"),
+                };
+                let population = match counted {
+                    true => histogram_population(&histogram, &palette),
+                    false => vec![],
+                };
+                let palette = (palette, population);
+                let outputs = outputs(0, 1);
+                emit(
+                    term,
+                    &args,
+                    "Combined",
+                    &name,
+                    palette,
+                    outputs,
+                    names.as_ref(),
+                )?;
+            }
+            Err(err) => files::skip_error(&mut skipped, "Combined", &err),
+        }
+    }
+
+    if let Some(path) = &args.report {
+        let html = report::render(&entries, &skipped, names.as_ref());
+        write_output(path, html.as_bytes())?;
+    }
+
+    files::report(term, &skipped, total)
+}
+
+/// Detects a solid background color from the border of an image.
+fn background(img: &image::DynamicImage) -> Option<Rgb24> {
+    let pixels = paletter::rgb_image_to_rgb24(&img.to_rgb8());
+    let (width, height) = (img.width() as usize, img.height() as usize);
+
+    filter::border_background(&pixels, width, height, Exclusion::DEFAULT_TOLERANCE)
+}
+
+/// Counts the pixels of a color histogram closest to each palette color.
+fn histogram_population(histogram: &HashMap<Rgb24, u64>, palette: &[Rgb24]) -> Vec<u64> {
+    let (colors, counts): (Vec<_>, Vec<_>) = histogram
+        .iter()
+        .map(|(&color, &count)| (color, count))
+        .unzip();
+    let mut population = vec![0; palette.len()];
+
+    for (i, count) in remap::indices(&colors, palette).into_iter().zip(counts) {
+        population[i] += count;
+    }
+
+    population
+}
+
+/// Pixels of an input image.
+struct Pixels {
+    colors: Vec<Rgb24>,
+    /// The same pixels with their weights, when weighting pixels.
+    weighted: Option<Vec<(Rgb24, u64)>>,
+}
+
+/// Result of processing an input image.
+enum Processed {
+    /// Pixels to combine with those of the other images.
+    Pixels(Pixels),
+    /// Quantized palette and its population, which may be empty.
+    Palette(Vec<Rgb24>, Vec<u64>),
+}
+
+/// Total weight of an image with `--equal-weight`, shared among its pixels.
+const IMAGE_WEIGHT: u64 = 1 << 32;
+
+/// Adds the pixels of an image to a combined palette. With `equal_weight`,
+/// each image is weighted inversely to its pixel count so that every image
+/// contributes equally.
+fn push_image(builder: &mut PaletteBuilder, pixels: &Pixels, equal_weight: bool) {
+    if pixels.colors.is_empty() {
+        return;
+    }
+
+    let scale = match equal_weight {
+        true => (IMAGE_WEIGHT / pixels.colors.len() as u64).max(1),
+        false => 1,
+    };
+
+    match &pixels.weighted {
+        Some(weighted) => {
+            let weighted: Vec<_> = weighted
+                .iter()
+                .map(|&(color, weight)| (color, weight.saturating_mul(scale)))
+                .collect();
+            builder.push_pixels(&weighted);
+        }
+        None => builder.push_weighted(&pixels.colors, scale),
+    }
+}
+
+/// Orders a palette along with its population, which may be empty.
+pub fn arrange(
+    palette: Vec<Rgb24>,
+    population: Vec<u64>,
+    order: Option<SortOrder>,
+    reverse: bool,
+) -> (Vec<Rgb24>, Vec<u64>) {
+    let mut indices = match order {
+        Some(order) => sort::sort_indices(&palette, &population, order),
+        None => (0..palette.len()).collect(),
+    };
+
+    if reverse {
+        indices.reverse();
+    }
+
+    let population = match population.is_empty() {
+        true => population,
+        false => indices.iter().map(|&i| population[i]).collect(),
+    };
+
+    (
+        indices.into_iter().map(|i| palette[i]).collect(),
+        population,
+    )
+}
+
+/// Files written for each palette.
+struct Outputs {
+    /// Palette file and its format.
+    palette: Option<(PathBuf, PaletteFormat)>,
+    /// Swatch image and its raster format, or `None` for SVG.
+    swatch: Option<(PathBuf, Option<image::ImageFormat>)>,
+}
+
+/// Displays a palette and writes it to the output files, if any.
+/// `population` is empty unless pixel counts are needed, and `names` is
+/// only given with `--names`.
+fn emit(
+    term: &mut Term,
+    args: &ExtractArgs,
+    title: &str,
+    name: &str,
+    (palette, population): (Vec<Rgb24>, Vec<u64>),
+    outputs: Outputs,
+    names: Option<&ColorNames>,
+) -> CliResult {
+    let method = args.method.unwrap_or(Method::MedianCut);
+    let (palette, population) = arrange(palette, population, args.sort_order(), args.reverse);
+
+    let to_stdout = outputs
+        .palette
+        .as_ref()
+        .is_some_and(|(output, _)| output == Path::new(STD_PATH));
+
+    if !to_stdout {
+        term.heading(title, name)?;
+        if args.bars {
+            term.bars(&palette, &population, &args.display)?;
+        } else if let Some(names) = names {
+            for color in &palette {
+                term.color(color, &args.display)?;
+                if let Some((name, delta_e)) = names.nearest(color) {
+                    write!(term.stdout, "  {name}  \u{394}E {delta_e:.2}")?;
+                }
+                writeln!(term.stdout)?;
+            }
+        } else {
+            term.palette(&palette, &args.display)?;
+        }
+        writeln!(term.stdout)?;
+
+        // Any method comes up short on inputs with few distinct colors.
+        if !method.exact_size() || palette.len() != args.palette_size.unwrap_or_default() {
+            term.note(&format!("Actual palette size: {}", palette.len()))?;
+        }
+
+        for &deficiency in &args.simulate {
+            simulate(term, args, &palette, deficiency)?;
+        }
+    }
+
+    if let Some((output, format)) = outputs.palette {
+        // Named colors get named variables in code formats.
+        let labels: Vec<&str> = names
+            .map(|names| {
+                palette
+                    .iter()
+                    .map(|color| names.nearest(color).map_or("", |(name, _)| name))
+                    .collect()
+            })
+            .unwrap_or_default();
+        write_output(&output, format.write_named(&palette, &labels).as_bytes())?;
+    }
+
+    if let Some((output, format)) = outputs.swatch {
+        let options = SwatchOptions {
+            layout: args.swatch_layout,
+            columns: args.swatch_columns,
+            labels: args.labels,
+            ..Default::default()
+        };
+
+        let bytes = match format {
+            Some(format) => {
+                let img = swatch::render_image(&palette, &population, &options);
+                encode_image(img, format)?
+            }
+            None => swatch::render_svg(&palette, &population, &options).into_bytes(),
+        };
+        write_output(&output, &bytes)?;
+    }
+
+    Ok(())
+}
+
+/// Displays a palette as seen with a color vision deficiency, followed by
+/// the pairs of colors that become indistinguishable.
+fn simulate(
+    term: &mut Term,
+    args: &ExtractArgs,
+    palette: &[Rgb24],
+    deficiency: Deficiency,
+) -> CliResult {
+    let name = match deficiency {
+        Deficiency::Protanopia => "Protanopia",
+        Deficiency::Deuteranopia => "Deuteranopia",
+        Deficiency::Tritanopia => "Tritanopia",
+    };
+
+    term.note(&format!("{name}:"))?;
+    term.palette(&cvd::simulate(palette, deficiency), &args.display)?;
+
+    let threshold = args.cvd_threshold.unwrap_or(cvd::DEFAULT_THRESHOLD);
+    for confusion in cvd::confusions(palette, deficiency, threshold) {
+        write!(term.stdout, "Indistinguishable: ")?;
+        term.color(&palette[confusion.first], &args.display)?;
+        write!(term.stdout, " and ")?;
+        term.color(&palette[confusion.second], &args.display)?;
+        writeln!(term.stdout, "  \u{394}E {:.2}", confusion.delta_e)?;
+    }
+
+    writeln!(term.stdout)?;
+    Ok(())
+}
+
+/// Checks whether a path has an SVG extension.
+fn is_svg(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("svg"))
+}
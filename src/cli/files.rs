@@ -0,0 +1,235 @@
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::{CliResult, Status, Term, STD_PATH};
+
+/// Input that could not be processed, with the reason.
+pub struct Skipped {
+    pub path: String,
+    pub reason: String,
+    pub status: Status,
+}
+
+/// Expands input arguments into a list of image paths.
+///
+/// Directories are searched recursively for files with a supported image
+/// extension, and glob patterns are matched against the filesystem with the
+/// same filter. Other arguments, including `-` for stdin, are kept as is.
+/// Files reached more than once, through overlapping arguments or symbolic
+/// links, are only listed the first time. Arguments that cannot be expanded
+/// are returned as skipped.
+///
+pub fn expand(args: &[String]) -> (Vec<String>, Vec<Skipped>) {
+    let mut expansion = Expansion::default();
+
+    for arg in args {
+        let path = Path::new(arg);
+
+        if arg != STD_PATH && path.is_dir() {
+            expansion.walk(path);
+        } else if !path.exists() && is_pattern(arg) {
+            match glob::glob(arg) {
+                Ok(paths) => {
+                    let count = expansion.files.len();
+
+                    for entry in paths {
+                        match entry {
+                            Ok(path) if path.is_dir() => expansion.walk(&path),
+                            Ok(path) if is_image(&path) => expansion.push(&path),
+                            Ok(_) => (),
+                            Err(err) => {
+                                let status = Status::from_io_error(err.error());
+                                skip(
+                                    &mut expansion.skipped,
+                                    &err.path().to_string_lossy(),
+                                    err.error(),
+                                    status,
+                                )
+                            }
+                        }
+                    }
+
+                    if expansion.files.len() == count {
+                        let skipped = &mut expansion.skipped;
+                        skip(skipped, arg, "no matching files", Status::NotFound);
+                    }
+                }
+                Err(err) => skip(&mut expansion.skipped, arg, err, Status::NotFound),
+            }
+        } else if arg == STD_PATH {
+            expansion.files.push(arg.clone());
+        } else {
+            expansion.push(path);
+        }
+    }
+
+    (expansion.files, expansion.skipped)
+}
+
+/// Files and skipped inputs collected by [`expand`].
+#[derive(Default)]
+struct Expansion {
+    files: Vec<String>,
+    skipped: Vec<Skipped>,
+    /// Canonical paths of the files listed so far.
+    seen: HashSet<PathBuf>,
+    /// Canonical paths of the directories walked so far.
+    visited: HashSet<PathBuf>,
+}
+
+impl Expansion {
+    /// Lists a file unless it was already listed.
+    /// Missing files are kept so that they are reported later.
+    fn push(&mut self, path: &Path) {
+        let canonical = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+
+        if self.seen.insert(canonical) {
+            self.files.push(path.to_string_lossy().into_owned());
+        }
+    }
+
+    /// Recursively collects image files in a directory, in path order.
+    /// Unreadable directories are skipped, and directories reached again
+    /// through symbolic links are not walked twice.
+    fn walk(&mut self, dir: &Path) {
+        let canonical = fs::canonicalize(dir).unwrap_or_else(|_| dir.to_path_buf());
+        if !self.visited.insert(canonical) {
+            return;
+        }
+
+        let entries = fs::read_dir(dir).and_then(|entries| {
+            entries
+                .map(|entry| entry.map(|e| e.path()))
+                .collect::<io::Result<Vec<_>>>()
+        });
+
+        let mut entries = match entries {
+            Ok(entries) => entries,
+            Err(err) => {
+                let status = Status::from_io_error(&err);
+                return skip(&mut self.skipped, &dir.to_string_lossy(), err, status);
+            }
+        };
+        entries.sort();
+
+        for path in entries {
+            if path.is_dir() {
+                self.walk(&path);
+            } else if is_image(&path) {
+                self.push(&path);
+            }
+        }
+    }
+}
+
+/// Lists the inputs that could not be processed.
+/// Returns the status of the first skipped input, if any.
+pub fn report(term: &mut Term, skipped: &[Skipped], total: usize) -> CliResult<Status> {
+    let Some(first) = skipped.first() else {
+        return Ok(Status::Success);
+    };
+
+    term.error(&format!("Skipped {} of {total} inputs:", skipped.len()))?;
+    for Skipped { path, reason, .. } in skipped {
+        term.error(&format!("  {path}: {reason}"))?;
+    }
+
+    Ok(first.status)
+}
+
+/// Records a skipped input.
+pub fn skip(skipped: &mut Vec<Skipped>, path: &str, reason: impl ToString, status: Status) {
+    skipped.push(Skipped {
+        path: path.to_string(),
+        reason: reason.to_string(),
+        status,
+    });
+}
+
+/// Records an input skipped due to a library error.
+pub fn skip_error(skipped: &mut Vec<Skipped>, path: &str, err: &paletter::Error) {
+    skip(skipped, path, err, Status::from_error(err));
+}
+
+/// Checks whether an argument contains glob metacharacters.
+fn is_pattern(arg: &str) -> bool {
+    arg.contains(['*', '?', '['])
+}
+
+/// Checks whether a file has an extension of a readable image format.
+fn is_image(path: &Path) -> bool {
+    image::ImageFormat::from_path(path).is_ok_and(|format| format.reading_enabled())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Creates an empty scratch directory for a test.
+    fn scratch(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("paletter-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("sub")).unwrap();
+        for file in ["a.png", "b.txt", "sub/c.png"] {
+            fs::write(dir.join(file), []).unwrap();
+        }
+        dir
+    }
+
+    fn arg(path: &Path) -> String {
+        path.to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn files_expand_dir() {
+        let dir = scratch("dir");
+        let expected = vec![arg(&dir.join("a.png")), arg(&dir.join("sub/c.png"))];
+
+        let (files, skipped) = expand(&[arg(&dir)]);
+        assert_eq!(files, expected);
+        assert!(skipped.is_empty());
+
+        // Globs are filtered like directories.
+        let (files, _) = expand(&[arg(&dir.join("*"))]);
+        assert_eq!(files, expected);
+
+        // Overlapping arguments list files once.
+        let (files, _) = expand(&[arg(&dir.join("a.png")), arg(&dir)]);
+        assert_eq!(files, vec![expected[0].clone(), expected[1].clone()]);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn files_expand_symlink_loop() {
+        let dir = scratch("loop");
+        std::os::unix::fs::symlink("..", dir.join("sub/loop")).unwrap();
+
+        let (files, skipped) = expand(&[arg(&dir)]);
+        assert_eq!(files.len(), 2);
+        assert!(skipped.is_empty());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn files_expand_missing() {
+        let dir = scratch("missing");
+
+        // Missing files are reported when opened, unmatched patterns now.
+        let missing = arg(&dir.join("missing.png"));
+        let (files, skipped) = expand(&[missing.clone(), STD_PATH.to_string()]);
+        assert_eq!(files, vec![missing, STD_PATH.to_string()]);
+        assert!(skipped.is_empty());
+
+        let (files, skipped) = expand(&[arg(&dir.join("*.gif"))]);
+        assert!(files.is_empty());
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].status, Status::NotFound);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
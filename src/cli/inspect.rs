@@ -0,0 +1,105 @@
+use std::io::Write;
+
+use clap::Args;
+use rayon::prelude::*;
+
+use paletter::metrics::ColorStats;
+
+use super::files;
+use super::{display_name, open_image, CliResult, DisplayArgs, Status, Term};
+
+#[derive(Args, Debug)]
+pub struct InspectArgs {
+    /// List of image file paths, directories or glob patterns.
+    /// Use `-` to read an image from stdin.
+    #[arg(required = true, num_args = 1..)]
+    files: Vec<String>,
+
+    /// Alpha channel threshold.
+    #[clap(long, short)]
+    alpha_thresh: Option<u8>,
+
+    /// Number of most frequent colors to list.
+    #[clap(long, default_value_t = 8)]
+    top: usize,
+
+    #[command(flatten)]
+    display: DisplayArgs,
+}
+
+/// Color statistics of an input image.
+struct Summary {
+    width: u32,
+    height: u32,
+    transparent: usize,
+    /// Statistics of the opaque pixels, if any.
+    stats: Option<ColorStats>,
+}
+
+/// Shows color statistics of each input image.
+pub fn run(args: InspectArgs, term: &mut Term) -> CliResult<Status> {
+    let alpha_min = args.alpha_thresh.unwrap_or(0);
+
+    let (files, mut skipped) = files::expand(&args.files);
+    let total = files.len() + skipped.len();
+
+    // Images are decoded and measured in parallel, then reported in order.
+    let results: Vec<_> = files
+        .par_iter()
+        .map(|path| {
+            open_image(path).map(|img| {
+                let img = img.to_rgba8();
+                let colors = paletter::rgba_image_to_rgb24(&img, alpha_min);
+                let pixels = img.width() as usize * img.height() as usize;
+
+                Summary {
+                    width: img.width(),
+                    height: img.height(),
+                    transparent: pixels - colors.len(),
+                    stats: ColorStats::from_colors(&colors),
+                }
+            })
+        })
+        .collect();
+
+    for (i, (path, result)) in files.iter().zip(results).enumerate() {
+        let summary = match result {
+            Ok(summary) => summary,
+            Err(err) => {
+                files::skip_error(&mut skipped, display_name(path), &err);
+                continue;
+            }
+        };
+
+        let (width, height) = (summary.width, summary.height);
+
+        term.heading(&format!("Image {}", i + 1), display_name(path))?;
+        writeln!(term.stdout, "Dimensions: {width}x{height}")?;
+        writeln!(term.stdout, "Pixels: {}", width as usize * height as usize)?;
+        writeln!(term.stdout, "Transparent: {}", summary.transparent)?;
+
+        let Some(stats) = summary.stats else {
+            writeln!(term.stdout)?;
+            continue;
+        };
+
+        writeln!(term.stdout, "Unique colors: {}", stats.unique)?;
+
+        for (label, color) in [("Mean", stats.mean), ("Min", stats.min), ("Max", stats.max)] {
+            write!(term.stdout, "{label}: ")?;
+            term.color(&color, &args.display)?;
+            writeln!(term.stdout)?;
+        }
+
+        writeln!(term.stdout, "Most frequent:")?;
+        for (color, count) in stats.histogram.iter().take(args.top) {
+            let share = 100.0 * *count as f64 / stats.count as f64;
+            term.color(color, &args.display)?;
+            writeln!(term.stdout, "  {count} ({share:.2}%)")?;
+        }
+
+        writeln!(term.stdout)?;
+    }
+
+    files::report(term, &skipped, total)
+}
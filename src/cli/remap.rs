@@ -0,0 +1,67 @@
+use std::path::PathBuf;
+
+use clap::{ArgGroup, Args};
+
+use paletter::cvd::{self, Deficiency};
+use paletter::{Method, PaletteFormat};
+
+use super::{encode_image, image_format, open_image, read_palette, write_output, CliResult};
+
+#[derive(Args, Debug)]
+#[command(group(ArgGroup::new("source").required(true).args(["palette", "size"])))]
+pub struct RemapArgs {
+    /// Image file path. Use `-` to read from stdin.
+    input: String,
+
+    /// Output image path. Use `-` to write to stdout.
+    output: PathBuf,
+
+    /// Palette file to map onto.
+    #[clap(long, short)]
+    palette: Option<String>,
+
+    /// Palette file format. Guessed from the palette extension by default.
+    #[clap(long, requires = "palette")]
+    palette_format: Option<PaletteFormat>,
+
+    /// Quantize a palette of this size from the image itself.
+    #[clap(long, short = 'n')]
+    size: Option<usize>,
+
+    /// Quantization method used with `--size`.
+    #[clap(long, requires = "size")]
+    method: Option<Method>,
+
+    /// Output image format, such as `png` or `jpeg`. Guessed from the
+    /// output extension by default, or PNG when writing to stdout.
+    #[clap(long)]
+    format: Option<String>,
+
+    /// Write the remapped image as seen with a color vision deficiency.
+    #[clap(long, value_name = "DEFICIENCY")]
+    simulate: Option<Deficiency>,
+}
+
+/// Maps the pixels of an image onto a palette and writes the result.
+pub fn run(args: RemapArgs) -> CliResult {
+    let img = open_image(&args.input)?.to_rgba8();
+
+    let palette = match (&args.palette, args.size) {
+        (Some(path), _) => read_palette(path, args.palette_format)?,
+        (None, Some(size)) => {
+            let colors = paletter::rgba_image_to_rgb24(&img, u8::MIN);
+            let method = args.method.unwrap_or(Method::MedianCut);
+            paletter::solve(&method, colors, size)?
+        }
+        (None, None) => unreachable!("clap requires a palette source"),
+    };
+
+    let format = image_format(&args.output, args.format.as_deref())?;
+    let mut remapped = paletter::remap::remap_image(&img, &palette);
+    if let Some(deficiency) = args.simulate {
+        remapped = cvd::simulate_image(&remapped, deficiency);
+    }
+    write_output(&args.output, &encode_image(remapped, format)?)?;
+
+    Ok(())
+}
@@ -0,0 +1,313 @@
+use std::fmt::Write;
+use std::io::Cursor;
+
+use clap::ValueEnum;
+use image::DynamicImage;
+
+use paletter::color::Rgb24;
+use paletter::{metrics, remap, ColorNames, Method, SortOrder};
+
+use super::extract::arrange;
+use super::files::Skipped;
+
+/// Largest side of the thumbnails in the report, in pixels.
+const THUMBNAIL_SIZE: u32 = 256;
+
+/// Quantization result of one method for a report entry.
+struct MethodResult {
+    method: Method,
+    palette: Vec<Rgb24>,
+    population: Vec<u64>,
+    /// Thumbnail remapped onto the palette, as a data URI.
+    preview: String,
+    mse: f64,
+    psnr: f64,
+    delta_e: f64,
+}
+
+/// Report section of one input image.
+pub struct Entry {
+    name: String,
+    width: u32,
+    height: u32,
+    /// Original thumbnail, as a data URI.
+    original: String,
+    /// Results of every method, the selected one first.
+    results: Vec<MethodResult>,
+}
+
+impl Entry {
+    /// Quantizes an image with every method and measures the results.
+    ///
+    /// `colors` are the image pixels above the alpha threshold, and `solve`
+    /// quantizes them with a method. Fails if the selected method fails;
+    /// other methods that fail are left out of the entry.
+    ///
+    pub fn new(
+        name: &str,
+        img: &DynamicImage,
+        colors: &[Rgb24],
+        selected: Method,
+        solve: &dyn Fn(Method) -> paletter::Result<Vec<Rgb24>>,
+        order: Option<SortOrder>,
+        reverse: bool,
+    ) -> paletter::Result<Self> {
+        let thumbnail = img.thumbnail(THUMBNAIL_SIZE, THUMBNAIL_SIZE).to_rgba8();
+
+        let mut methods = Method::value_variants().to_vec();
+        methods.sort_by_key(|&method| method != selected);
+
+        let measure = |method| {
+            let palette = solve(method)?;
+            let population = remap::population(colors, &palette);
+            let (palette, population) = arrange(palette, population, order, reverse);
+
+            let remapped = remap::remap(colors, &palette);
+            let preview = remap::remap_image(&thumbnail, &palette);
+
+            Ok(MethodResult {
+                method,
+                population,
+                preview: data_uri(preview)?,
+                mse: metrics::mse(colors, &remapped),
+                psnr: metrics::psnr(colors, &remapped),
+                delta_e: metrics::mean_delta_e(colors, &remapped),
+                palette,
+            })
+        };
+
+        let results = methods
+            .into_iter()
+            .filter_map(|method| match measure(method) {
+                Err(_) if method != selected => None,
+                result => Some(result),
+            })
+            .collect::<paletter::Result<_>>()?;
+
+        Ok(Self {
+            name: name.to_string(),
+            width: img.width(),
+            height: img.height(),
+            original: data_uri(thumbnail)?,
+            results,
+        })
+    }
+}
+
+/// Builds a self-contained HTML page of the report entries and skipped inputs.
+/// Swatches are labeled with their closest name when `names` are given.
+pub fn render(entries: &[Entry], skipped: &[Skipped], names: Option<&ColorNames>) -> String {
+    let mut out = String::new();
+
+    out.push_str(concat!(
+        "<!DOCTYPE html>\n",
+        "<html lang=\"en\">\n",
+        "<head>\n",
+        "<meta charset=\"utf-8\">\n",
+        "<title>Paletter report</title>\n",
+        "<style>\n",
+        "body { font-family: sans-serif; margin: 2em; background: #f4f4f4; color: #222; }\n",
+        "section { background: #fff; padding: 1em; margin-bottom: 1.5em; border-radius: 6px; }\n",
+        ".images { display: flex; gap: 1em; }\n",
+        "figure { margin: 0; }\n",
+        "figure img { max-width: 256px; image-rendering: pixelated; background: repeating-conic-gradient(#ddd 0 25%, #fff 0 50%) 0 0 / 16px 16px; }\n",
+        ".swatches { display: flex; flex-wrap: wrap; gap: 4px; margin: 1em 0; }\n",
+        ".swatch { width: 84px; height: 64px; font: 12px monospace; display: flex; flex-direction: column; justify-content: center; align-items: center; }\n",
+        ".swatch span { max-width: 80px; overflow: hidden; text-overflow: ellipsis; white-space: nowrap; }\n",
+        "table { border-collapse: collapse; }\n",
+        "th, td { padding: 0.25em 1em; text-align: right; border-bottom: 1px solid #ddd; }\n",
+        "th:first-child, td:first-child { text-align: left; }\n",
+        "</style>\n",
+        "</head>\n",
+        "<body>\n",
+        "<h1>Paletter report</h1>\n",
+    ));
+
+    for entry in entries {
+        write_entry(&mut out, entry, names);
+    }
+
+    if !skipped.is_empty() {
+        out.push_str("<section>\n<h2>Skipped inputs</h2>\n<ul>\n");
+        for Skipped { path, reason, .. } in skipped {
+            let _ = writeln!(out, "<li>{}: {}</li>", escape(path), escape(reason));
+        }
+        out.push_str("</ul>\n</section>\n");
+    }
+
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+/// Writes the section of one input image.
+fn write_entry(out: &mut String, entry: &Entry, names: Option<&ColorNames>) {
+    let selected = &entry.results[0];
+    let total: u64 = selected.population.iter().sum();
+
+    let _ = writeln!(out, "<section>\n<h2>{}</h2>", escape(&entry.name));
+    let _ = writeln!(out, "<p>{}x{}</p>", entry.width, entry.height);
+
+    let _ = writeln!(
+        out,
+        concat!(
+            "<div class=\"images\">\n",
+            "<figure><img src=\"{}\" alt=\"Original\"><figcaption>Original</figcaption></figure>\n",
+            "<figure><img src=\"{}\" alt=\"Quantized\"><figcaption>{} ({} colors)</figcaption></figure>\n",
+            "</div>",
+        ),
+        entry.original,
+        selected.preview,
+        method_name(selected.method),
+        selected.palette.len()
+    );
+
+    out.push_str("<div class=\"swatches\">\n");
+    for (color, &count) in selected.palette.iter().zip(&selected.population) {
+        let share = 100.0 * count as f64 / total.max(1) as f64;
+        let name = match names.and_then(|names| names.nearest(color)) {
+            Some((name, delta_e)) => format!(
+                "<span title=\"\u{394}E {delta_e:.2}\">{}</span>",
+                escape(name)
+            ),
+            None => String::new(),
+        };
+        let _ = writeln!(
+            out,
+            "<div class=\"swatch\" style=\"background: {}; color: {}\"><span>{}</span>{name}<span>{share:.1}%</span></div>",
+            color.to_hex_string(),
+            color.contrasting().to_hex_string(),
+            color.to_hex_string()
+        );
+    }
+    out.push_str("</div>\n");
+
+    out.push_str("<table>\n<tr><th>Method</th><th>Colors</th><th>MSE</th><th>PSNR</th><th>Mean \u{394}E</th></tr>\n");
+    for result in &entry.results {
+        let _ = writeln!(
+            out,
+            "<tr><td>{}</td><td>{}</td><td>{:.2}</td><td>{:.2} dB</td><td>{:.2}</td></tr>",
+            method_name(result.method),
+            result.palette.len(),
+            result.mse,
+            result.psnr,
+            result.delta_e
+        );
+    }
+    out.push_str("</table>\n</section>\n");
+}
+
+/// Gets the command-line name of a method.
+fn method_name(method: Method) -> String {
+    method
+        .to_possible_value()
+        .map(|value| value.get_name().to_string())
+        .unwrap_or_default()
+}
+
+/// Escapes text for use in HTML.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Encodes an image as a base64 PNG data URI.
+fn data_uri(img: image::RgbaImage) -> paletter::Result<String> {
+    let mut png = Cursor::new(Vec::new());
+    DynamicImage::ImageRgba8(img).write_to(&mut png, image::ImageFormat::Png)?;
+
+    Ok(format!("data:image/png;base64,{}", base64(png.get_ref())))
+}
+
+/// Encodes bytes in base64 with padding.
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+
+    for chunk in bytes.chunks(3) {
+        let byte = |i: usize| chunk.get(i).copied().unwrap_or_default();
+        let n = u32::from_be_bytes([0, byte(0), byte(1), byte(2)]);
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 0x3F) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::Status;
+    use super::*;
+
+    fn entry(solve: &dyn Fn(Method) -> paletter::Result<Vec<Rgb24>>) -> paletter::Result<Entry> {
+        let img = image::RgbImage::from_fn(4, 4, |x, _| match x < 2 {
+            true => image::Rgb([200, 30, 30]),
+            false => image::Rgb([20, 40, 200]),
+        });
+        let img = DynamicImage::ImageRgb8(img);
+        let colors = paletter::rgb_image_to_rgb24(&img.to_rgb8());
+
+        Entry::new("<a.png>", &img, &colors, Method::Octree, solve, None, false)
+    }
+
+    #[test]
+    fn report_render() {
+        let palette = vec![Rgb24::new(200, 30, 30), Rgb24::new(20, 40, 200)];
+        let entry = entry(&|_| Ok(palette.clone())).unwrap();
+        assert_eq!(entry.results.len(), 2);
+        assert_eq!(entry.results[0].method, Method::Octree);
+        assert_eq!(entry.results[0].population, vec![8, 8]);
+
+        let skipped = [Skipped {
+            path: "b.png".into(),
+            reason: "file not found".into(),
+            status: Status::NotFound,
+        }];
+        let html = render(&[entry], &skipped, None);
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<h2>&lt;a.png&gt;</h2>"));
+        assert!(html.contains("<figcaption>octree (2 colors)</figcaption>"));
+        assert!(html.contains("<td>median-cut</td>"));
+        assert!(html.contains("<span>#C81E1E</span>"));
+        assert!(html.contains("<li>b.png: file not found</li>"));
+        assert!(html.ends_with("</html>\n"));
+    }
+
+    #[test]
+    fn report_failed_method() {
+        // Other methods are dropped on failure, unlike the selected one.
+        let partial = entry(&|method| match method {
+            Method::Octree => Ok(vec![Rgb24::new(0, 0, 0)]),
+            Method::MedianCut => Err(paletter::Error::EmptyInput),
+        })
+        .unwrap();
+        assert_eq!(partial.results.len(), 1);
+
+        assert!(entry(&|_| Err(paletter::Error::EmptyInput)).is_err());
+    }
+
+    #[test]
+    fn report_base64() {
+        // Test vectors of RFC 4648.
+        for (text, encoded) in [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ] {
+            assert_eq!(base64(text.as_bytes()), encoded);
+        }
+    }
+}
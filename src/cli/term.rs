@@ -0,0 +1,403 @@
+use std::env;
+use std::ffi::OsString;
+use std::io::{self, IsTerminal, Write};
+
+use clap::ValueEnum;
+use termcolor::{self, ColorChoice, ColorSpec, StandardStream, WriteColor};
+
+use paletter::color::Rgb24;
+use paletter::remap;
+
+use super::DisplayArgs;
+
+/// When to style terminal output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum ColorMode {
+    /// Style output written to a terminal, honoring `NO_COLOR` and `CLICOLOR_FORCE`.
+    #[default]
+    Auto,
+    /// Always style output.
+    Always,
+    /// Never style output.
+    Never,
+}
+
+impl ColorMode {
+    /// Decides whether to style a stream.
+    fn choice(self, is_terminal: bool) -> ColorChoice {
+        self.choice_with(is_terminal, |name| env::var_os(name))
+    }
+
+    /// Decides whether to style a stream, reading environment variables
+    /// through `var`.
+    ///
+    /// `NO_COLOR` wins over `CLICOLOR_FORCE`, which only forces styling
+    /// when set to a value other than `0`. Otherwise styling depends on
+    /// whether the stream is a terminal.
+    ///
+    fn choice_with(self, is_terminal: bool, var: impl Fn(&str) -> Option<OsString>) -> ColorChoice {
+        let set = |name| var(name).is_some_and(|value| !value.is_empty());
+
+        let enabled = match self {
+            ColorMode::Always => true,
+            ColorMode::Never => false,
+            ColorMode::Auto => {
+                if set("NO_COLOR") {
+                    false
+                } else if var("CLICOLOR_FORCE").is_some_and(|v| !v.is_empty() && v != "0") {
+                    true
+                } else {
+                    is_terminal && var("TERM").is_none_or(|term| term != "dumb")
+                }
+            }
+        };
+
+        if enabled {
+            ColorChoice::AlwaysAnsi
+        } else {
+            ColorChoice::Never
+        }
+    }
+}
+
+/// Number of colors the terminal can display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ColorDepth {
+    TrueColor,
+    Ansi256,
+    Ansi16,
+}
+
+impl ColorDepth {
+    /// Guesses the color depth from the environment.
+    fn detect() -> Self {
+        let colorterm = env::var("COLORTERM").unwrap_or_default();
+        let term = env::var("TERM").unwrap_or_default();
+
+        if colorterm == "truecolor" || colorterm == "24bit" || cfg!(windows) {
+            ColorDepth::TrueColor
+        } else if term.contains("256color") {
+            ColorDepth::Ansi256
+        } else if term.is_empty() || term.contains("color") || term.starts_with("xterm") {
+            // Most terminals advertise truecolor through COLORTERM, but many
+            // that do not still understand 256 colors.
+            ColorDepth::Ansi256
+        } else {
+            ColorDepth::Ansi16
+        }
+    }
+}
+
+/// Default RGB values of the 16 ANSI colors, as used by xterm.
+const ANSI16: [Rgb24; 16] = [
+    Rgb24::new(0, 0, 0),
+    Rgb24::new(205, 0, 0),
+    Rgb24::new(0, 205, 0),
+    Rgb24::new(205, 205, 0),
+    Rgb24::new(0, 0, 238),
+    Rgb24::new(205, 0, 205),
+    Rgb24::new(0, 205, 205),
+    Rgb24::new(229, 229, 229),
+    Rgb24::new(127, 127, 127),
+    Rgb24::new(255, 0, 0),
+    Rgb24::new(0, 255, 0),
+    Rgb24::new(255, 255, 0),
+    Rgb24::new(92, 92, 255),
+    Rgb24::new(255, 0, 255),
+    Rgb24::new(0, 255, 255),
+    Rgb24::new(255, 255, 255),
+];
+
+/// Channel levels of the 6x6x6 color cube in the 256-color palette.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Finds the closest color in the 256-color palette.
+fn to_ansi256(color: &Rgb24) -> u8 {
+    let level = |c: u8| {
+        (0..CUBE_LEVELS.len())
+            .min_by_key(|&i| CUBE_LEVELS[i].abs_diff(c))
+            .unwrap() as u8
+    };
+
+    let (r, g, b) = (level(color.r()), level(color.g()), level(color.b()));
+    let cube = Rgb24::new(
+        CUBE_LEVELS[r as usize],
+        CUBE_LEVELS[g as usize],
+        CUBE_LEVELS[b as usize],
+    );
+
+    // Grayscale ramp from 8 to 238 in steps of 10.
+    let mean = (color.r() as u16 + color.g() as u16 + color.b() as u16) / 3;
+    let step = (mean.saturating_sub(3) / 10).min(23) as u8;
+    let gray = Rgb24::new(8 + step * 10, 8 + step * 10, 8 + step * 10);
+
+    if gray.distance_squared(color) < cube.distance_squared(color) {
+        232 + step
+    } else {
+        16 + 36 * r + 6 * g + b
+    }
+}
+
+/// Finds the closest of the 16 ANSI colors.
+fn to_ansi16(color: &Rgb24) -> termcolor::Color {
+    let index = remap::nearest(&ANSI16, color).unwrap();
+
+    match index {
+        0 => termcolor::Color::Black,
+        1 => termcolor::Color::Red,
+        2 => termcolor::Color::Green,
+        3 => termcolor::Color::Yellow,
+        4 => termcolor::Color::Blue,
+        5 => termcolor::Color::Magenta,
+        6 => termcolor::Color::Cyan,
+        7 => termcolor::Color::White,
+        // Bright variants.
+        _ => termcolor::Color::Ansi256(index as u8),
+    }
+}
+
+/// Width of the longest population bar, in characters.
+const BAR_WIDTH: usize = 32;
+
+/// Terminal output streams and shared styles.
+pub struct Term {
+    pub stdout: StandardStream,
+    pub stderr: StandardStream,
+    depth: ColorDepth,
+    bold_spec: ColorSpec,
+    err_spec: ColorSpec,
+    note_spec: ColorSpec,
+}
+
+impl Term {
+    /// Opens the standard output streams.
+    pub fn new(mode: ColorMode) -> Self {
+        let depth = ColorDepth::detect();
+
+        let mut bold_spec = ColorSpec::new();
+        bold_spec.set_bold(true);
+
+        let mut err_spec = ColorSpec::new();
+        err_spec.set_fg(Some(termcolor::Color::Red));
+
+        let mut note_spec = ColorSpec::new();
+        note_spec.set_fg(Some(ansi_color(depth, &Rgb24::new(91, 94, 97))));
+        note_spec.set_italic(true);
+
+        Self {
+            stdout: StandardStream::stdout(mode.choice(io::stdout().is_terminal())),
+            stderr: StandardStream::stderr(mode.choice(io::stderr().is_terminal())),
+            depth,
+            bold_spec,
+            err_spec,
+            note_spec,
+        }
+    }
+
+    /// Writes a bold heading followed by plain text.
+    pub fn heading(&mut self, title: &str, text: &str) -> io::Result<()> {
+        self.stdout.set_color(&self.bold_spec)?;
+        write!(self.stdout, "{title}")?;
+        self.stdout.reset()?;
+        writeln!(self.stdout, ": {text}")
+    }
+
+    /// Writes an error message to stderr.
+    pub fn error(&mut self, message: &str) -> io::Result<()> {
+        self.stderr.set_color(&self.err_spec)?;
+        write!(self.stderr, "{message}")?;
+        self.stderr.reset()?;
+        writeln!(self.stderr)
+    }
+
+    /// Writes a dimmed note.
+    pub fn note(&mut self, message: &str) -> io::Result<()> {
+        self.stdout.set_color(&self.note_spec)?;
+        write!(self.stdout, "{message}")?;
+        self.stdout.reset()?;
+        writeln!(self.stdout)
+    }
+
+    /// Writes a single color in the requested display formats.
+    ///
+    /// In swatch mode, the text is written on a block of the color itself.
+    ///
+    pub fn color(&mut self, color: &Rgb24, display: &DisplayArgs) -> io::Result<()> {
+        let rgb = display.rgb || !display.hex;
+        let hex = display.hex;
+
+        let mut text = String::new();
+        if rgb {
+            text.push_str(&color.to_string());
+            hex.then(|| text.push(' '));
+        }
+        if hex {
+            text.push_str(&color.to_hex_string());
+        }
+
+        if display.is_swatch() {
+            let mut color_spec = ColorSpec::new();
+            color_spec
+                .set_fg(Some(self.ansi_color(&color.contrasting())))
+                .set_bg(Some(self.ansi_color(color)));
+
+            self.stdout.set_color(&color_spec)?;
+            write!(self.stdout, " {text} ")?;
+        } else {
+            if !display.uncolored {
+                let mut color_spec = ColorSpec::new();
+                color_spec.set_fg(Some(self.ansi_color(color)));
+
+                self.stdout.set_color(&color_spec)?;
+            }
+
+            write!(self.stdout, "{text}")?;
+        }
+
+        self.stdout.reset()
+    }
+
+    /// Writes a text sample in one color on a background of another.
+    pub fn sample(&mut self, text: &str, fg: &Rgb24, bg: &Rgb24) -> io::Result<()> {
+        let mut color_spec = ColorSpec::new();
+        color_spec
+            .set_fg(Some(self.ansi_color(fg)))
+            .set_bg(Some(self.ansi_color(bg)));
+
+        self.stdout.set_color(&color_spec)?;
+        write!(self.stdout, " {text} ")?;
+        self.stdout.reset()
+    }
+
+    /// Writes a palette, one color per line, or in rows in grid mode.
+    pub fn palette(&mut self, palette: &[Rgb24], display: &DisplayArgs) -> io::Result<()> {
+        let columns = display.grid.unwrap_or(1).max(1);
+
+        for row in palette.chunks(columns) {
+            for (i, color) in row.iter().enumerate() {
+                if i > 0 {
+                    write!(self.stdout, " ")?;
+                }
+                self.color(color, display)?;
+            }
+            writeln!(self.stdout)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes a palette with a bar showing how many pixels map to each color.
+    pub fn bars(
+        &mut self,
+        palette: &[Rgb24],
+        population: &[u64],
+        display: &DisplayArgs,
+    ) -> io::Result<()> {
+        let total: u64 = population.iter().sum();
+        let largest = population.iter().copied().max().unwrap_or_default().max(1);
+
+        for (color, &count) in palette.iter().zip(population) {
+            let width = (BAR_WIDTH as f64 * count as f64 / largest as f64).round() as usize;
+            let share = 100.0 * count as f64 / total.max(1) as f64;
+
+            self.color(color, display)?;
+            write!(self.stdout, " ")?;
+
+            if !display.uncolored {
+                let mut color_spec = ColorSpec::new();
+                color_spec.set_fg(Some(self.ansi_color(color)));
+                self.stdout.set_color(&color_spec)?;
+            }
+            write!(self.stdout, "{:<BAR_WIDTH$}", "\u{2588}".repeat(width))?;
+            self.stdout.reset()?;
+
+            writeln!(self.stdout, " {share:5.1}%")?;
+        }
+
+        Ok(())
+    }
+
+    /// Converts a color to the closest one the terminal can display.
+    fn ansi_color(&self, color: &Rgb24) -> termcolor::Color {
+        ansi_color(self.depth, color)
+    }
+}
+
+/// Converts a color to the closest one displayable at the given depth.
+fn ansi_color(depth: ColorDepth, color: &Rgb24) -> termcolor::Color {
+    match depth {
+        ColorDepth::TrueColor => termcolor::Color::Rgb(color.r(), color.g(), color.b()),
+        ColorDepth::Ansi256 => termcolor::Color::Ansi256(to_ansi256(color)),
+        ColorDepth::Ansi16 => to_ansi16(color),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Decides styling with the given environment variables.
+    fn choice(mode: ColorMode, is_terminal: bool, vars: &[(&str, &str)]) -> ColorChoice {
+        mode.choice_with(is_terminal, |name| {
+            vars.iter()
+                .find(|(var, _)| *var == name)
+                .map(|(_, value)| OsString::from(value))
+        })
+    }
+
+    #[test]
+    fn term_color_mode() {
+        use ColorChoice::{AlwaysAnsi, Never};
+        use ColorMode::Auto;
+
+        assert_eq!(choice(Auto, true, &[]), AlwaysAnsi);
+        assert_eq!(choice(Auto, false, &[]), Never);
+        assert_eq!(choice(Auto, true, &[("TERM", "dumb")]), Never);
+        assert_eq!(choice(Auto, true, &[("NO_COLOR", "1")]), Never);
+        assert_eq!(choice(Auto, true, &[("NO_COLOR", "")]), AlwaysAnsi);
+
+        // CLICOLOR_FORCE forces styling, unless it is 0 or NO_COLOR is set.
+        assert_eq!(choice(Auto, false, &[("CLICOLOR_FORCE", "1")]), AlwaysAnsi);
+        assert_eq!(choice(Auto, true, &[("CLICOLOR_FORCE", "0")]), AlwaysAnsi);
+        assert_eq!(choice(Auto, false, &[("CLICOLOR_FORCE", "0")]), Never);
+        let both = [("NO_COLOR", "1"), ("CLICOLOR_FORCE", "1")];
+        assert_eq!(choice(Auto, true, &both), Never);
+
+        // Explicit modes ignore the environment.
+        assert_eq!(choice(ColorMode::Always, false, &both), AlwaysAnsi);
+        assert_eq!(choice(ColorMode::Never, true, &[]), Never);
+    }
+
+    #[test]
+    fn term_ansi256() {
+        assert_eq!(to_ansi256(&Rgb24::new(0, 0, 0)), 16);
+        assert_eq!(to_ansi256(&Rgb24::new(255, 255, 255)), 231);
+        assert_eq!(to_ansi256(&Rgb24::new(255, 0, 0)), 196);
+        assert_eq!(to_ansi256(&Rgb24::new(95, 135, 175)), 67);
+
+        // Grays between cube levels use the grayscale ramp.
+        assert_eq!(to_ansi256(&Rgb24::new(128, 128, 128)), 244);
+        assert_eq!(to_ansi256(&Rgb24::new(8, 8, 8)), 232);
+    }
+
+    #[test]
+    fn term_ansi16() {
+        assert_eq!(to_ansi16(&Rgb24::new(10, 10, 10)), termcolor::Color::Black);
+        assert_eq!(to_ansi16(&Rgb24::new(200, 10, 10)), termcolor::Color::Red);
+        assert_eq!(
+            to_ansi16(&Rgb24::new(220, 220, 220)),
+            termcolor::Color::White
+        );
+
+        // Bright colors are addressed by index.
+        assert_eq!(
+            to_ansi16(&Rgb24::new(250, 250, 250)),
+            termcolor::Color::Ansi256(15)
+        );
+
+        assert_eq!(
+            ansi_color(ColorDepth::TrueColor, &Rgb24::new(1, 2, 3)),
+            termcolor::Color::Rgb(1, 2, 3)
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+
+use paletter::{Method, Theme, ThemeTarget};
+
+use super::{display_name, open_image, write_output, CliResult, Term, STD_PATH};
+
+#[derive(Args, Debug)]
+pub struct ThemeArgs {
+    /// Image file path. Use `-` to read from stdin.
+    input: String,
+
+    /// Terminal or settings format of the theme.
+    #[clap(long, short)]
+    target: ThemeTarget,
+
+    /// Output path. The theme is written to stdout by default.
+    #[clap(long, short)]
+    output: Option<PathBuf>,
+
+    /// Number of colors extracted from the image.
+    #[clap(long, short = 'n', default_value_t = 16)]
+    size: usize,
+
+    /// Quantization method.
+    #[clap(long)]
+    method: Option<Method>,
+
+    /// Alpha channel threshold.
+    #[clap(long, short)]
+    alpha_thresh: Option<u8>,
+
+    /// Generate a light theme instead of a dark one.
+    #[clap(long)]
+    light: bool,
+
+    /// Name of the color scheme, for the targets that name them.
+    /// Defaults to the image file name.
+    #[clap(long)]
+    name: Option<String>,
+}
+
+/// Generates a terminal color scheme from the palette of an image.
+pub fn run(args: ThemeArgs, term: &mut Term) -> CliResult {
+    let img = open_image(&args.input)?;
+    let colors = paletter::dynamic_image_to_rgb24(&img, args.alpha_thresh.unwrap_or(0));
+    let method = args.method.unwrap_or(Method::MedianCut);
+    let palette = paletter::solve(&method, colors, args.size)?;
+
+    let theme = Theme::from_palette(&palette, args.light);
+
+    let name = match &args.name {
+        Some(name) => name.clone(),
+        None => Path::new(display_name(&args.input))
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "paletter".to_string()),
+    };
+
+    let output = args.output.unwrap_or_else(|| PathBuf::from(STD_PATH));
+    write_output(&output, args.target.render(&theme, &name).as_bytes())?;
+
+    // Preview the ANSI colors on the background when writing to a file.
+    if output != Path::new(STD_PATH) {
+        term.heading("Theme", &name)?;
+        for row in theme.ansi.chunks(8) {
+            for color in row {
+                term.sample("Aa", color, &theme.background)?;
+            }
+            writeln!(term.stdout)?;
+        }
+    }
+
+    Ok(())
+}
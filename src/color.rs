@@ -1,6 +1,9 @@
 use std::cmp::Ordering;
 use std::fmt;
 use std::ops;
+use std::str::FromStr;
+
+use crate::error::Error;
 
 /// RGB channel.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -22,14 +25,14 @@ impl RGBChannel {
 }
 
 /// RGB24 representation.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct Rgb24 {
     channels: [u8; 3],
 }
 
 impl Rgb24 {
     /// Creates a new RGB24 color.
-    pub fn new(r: u8, g: u8, b: u8) -> Self {
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
         Self {
             channels: [r, g, b],
         }
@@ -79,11 +82,14 @@ impl Rgb24 {
     }
 
     /// Finds the channel with the greatest delta.
-    pub fn max_channel_delta(colors: &[Self]) -> (RGBChannel, u8) {
+    pub fn max_channel_delta<'a, I>(colors: I) -> (RGBChannel, u8)
+    where
+        I: IntoIterator<Item = &'a Self>,
+    {
         let high = Self::new(u8::MAX, u8::MAX, u8::MAX);
         let low = Self::new(u8::MIN, u8::MIN, u8::MIN);
 
-        let (min, max) = colors.iter().fold((high, low), |(min, max), val| {
+        let (min, max) = colors.into_iter().fold((high, low), |(min, max), val| {
             (Self::min(&min, val), Self::max(&max, val))
         });
 
@@ -117,14 +123,129 @@ impl Rgb24 {
         )
     }
 
+    /// Finds the channel-wise average of colors weighted by their counts.
+    pub fn weighted_average(colors: &[(Self, u64)]) -> Self {
+        // Sums are widened so that large weights cannot overflow.
+        let (r, g, b, total) = colors.iter().fold((0, 0, 0, 0), |sum, (val, weight)| {
+            let weight = *weight as u128;
+            (
+                sum.0 + val.r() as u128 * weight,
+                sum.1 + val.g() as u128 * weight,
+                sum.2 + val.b() as u128 * weight,
+                sum.3 + weight,
+            )
+        });
+
+        Self::new(
+            f64::round(r as f64 / total as f64) as u8,
+            f64::round(g as f64 / total as f64) as u8,
+            f64::round(b as f64 / total as f64) as u8,
+        )
+    }
+
     // Builds a hex representation string.
     pub fn to_hex_string(&self) -> String {
         format!("#{:02X}{:02X}{:02X}", self.r(), self.g(), self.b())
     }
 
+    /// Finds the relative luminance as defined by WCAG, from 0 (black) to 1 (white).
+    pub fn relative_luminance(&self) -> f32 {
+        0.2126 * srgb_to_linear(self.r())
+            + 0.7152 * srgb_to_linear(self.g())
+            + 0.0722 * srgb_to_linear(self.b())
+    }
+
+    /// Finds the WCAG contrast ratio to another color, from 1 to 21.
+    pub fn contrast_ratio(&self, other: &Self) -> f32 {
+        let (a, b) = (self.relative_luminance(), other.relative_luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Picks black or white, whichever is more legible on this color.
+    pub fn contrasting(&self) -> Self {
+        // Luminance where black and white text have the same contrast ratio.
+        if self.relative_luminance() > 0.179 {
+            Self::new(0, 0, 0)
+        } else {
+            Self::new(255, 255, 255)
+        }
+    }
+
+    /// Creates the corresponding CIE L*a*b* representation (D65 white point).
+    pub fn to_lab(&self) -> Lab {
+        let r = srgb_to_linear(self.r());
+        let g = srgb_to_linear(self.g());
+        let b = srgb_to_linear(self.b());
+
+        // Linear sRGB to XYZ, normalized by the D65 reference white.
+        let x = (0.4124564 * r + 0.3575761 * g + 0.1804375 * b) / 0.95047;
+        let y = 0.2126729 * r + 0.7151522 * g + 0.0721750 * b;
+        let z = (0.0193339 * r + 0.119192 * g + 0.9503041 * b) / 1.08883;
+
+        let f = |t: f32| {
+            if t > 216.0 / 24389.0 {
+                t.cbrt()
+            } else {
+                (24389.0 / 27.0 * t + 16.0) / 116.0
+            }
+        };
+
+        let (fx, fy, fz) = (f(x), f(y), f(z));
+
+        Lab {
+            l: 116.0 * fy - 16.0,
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+        }
+    }
+
+    /// Creates the corresponding Oklab representation.
+    pub fn to_oklab(&self) -> Oklab {
+        let r = srgb_to_linear(self.r());
+        let g = srgb_to_linear(self.g());
+        let b = srgb_to_linear(self.b());
+
+        // Linear sRGB to approximate cone responses.
+        let l = (0.4122215 * r + 0.5363325 * g + 0.051446 * b).cbrt();
+        let m = (0.2119035 * r + 0.6806995 * g + 0.107397 * b).cbrt();
+        let s = (0.0883025 * r + 0.2817188 * g + 0.6299787 * b).cbrt();
+
+        Oklab {
+            l: 0.2104543 * l + 0.7936178 * m - 0.004072 * s,
+            a: 1.9779985 * l - 2.4285922 * m + 0.4505937 * s,
+            b: 0.025904 * l + 0.7827718 * m - 0.8086758 * s,
+        }
+    }
+
+    /// Finds the perceptual distance to another color.
+    pub fn delta_e(&self, other: &Self) -> f32 {
+        self.to_lab().delta_e(&other.to_lab())
+    }
+
+    /// Finds the squared Euclidean distance to another color in RGB space.
+    pub fn distance_squared(&self, other: &Self) -> u32 {
+        self.channels
+            .iter()
+            .zip(other.channels.iter())
+            .map(|(&a, &b)| (a as i32 - b as i32).pow(2) as u32)
+            .sum()
+    }
+
     /// Creates the corresponding HSV representation.
     /// Hue has range [0, 180] so that it fits in a single byte.
     fn make_hsv(&self) -> Hsv {
+        let (h, s, v) = self.hsv();
+
+        Hsv::new(
+            f32::round(h / 2.0) as u8,
+            f32::round(100.0 * s) as u8,
+            f32::round(100.0 * v) as u8,
+        )
+    }
+
+    /// Finds the HSV hue in degrees, and the saturation and value in [0, 1].
+    /// Grays have a hue of 0.
+    pub(crate) fn hsv(&self) -> (f32, f32, f32) {
         let rp = self.r() as f32 / 255.0;
         let gp = self.g() as f32 / 255.0;
         let bp = self.b() as f32 / 255.0;
@@ -133,8 +254,8 @@ impl Rgb24 {
         let cmin = f32::min(rp, f32::min(gp, bp));
         let delta = cmax - cmin;
 
-        let h = 30.0
-            * if cmax == 0.0 {
+        let h = 60.0
+            * if delta == 0.0 {
                 0.0
             } else if cmax == rp {
                 ((gp - bp) / delta).rem_euclid(6.0)
@@ -144,15 +265,9 @@ impl Rgb24 {
                 (rp - gp) / delta + 4.0
             };
 
-        let s = 100.0 * if cmax != 0.0 { delta / cmax } else { 0.0 };
-
-        let v = 100.0 * cmax;
+        let s = if cmax != 0.0 { delta / cmax } else { 0.0 };
 
-        Hsv::new(
-            f32::round(h) as u8,
-            f32::round(s) as u8,
-            f32::round(v) as u8,
-        )
+        (h, s, cmax)
     }
 
     /// Sorts a slice of colors using channel-based radix sort.
@@ -162,7 +277,7 @@ impl Rgb24 {
         let mut buckets: Vec<Vec<Rgb24>> = vec![vec![]; 256];
         colors
             .iter()
-            .for_each(|c| buckets[c[channel] as usize].push(c.clone()));
+            .for_each(|c| buckets[c[channel] as usize].push(*c));
 
         for (i, color) in buckets.into_iter().flatten().enumerate() {
             colors[i] = color;
@@ -180,7 +295,7 @@ impl Rgb24 {
 
 impl PartialOrd for Rgb24 {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.make_hsv().cmp(&other.make_hsv()))
+        Some(self.cmp(other))
     }
 }
 
@@ -190,6 +305,36 @@ impl Ord for Rgb24 {
     }
 }
 
+impl FromStr for Rgb24 {
+    type Err = Error;
+
+    /// Parses a hexadecimal color such as `#FF8000`, `ff8000` or `#F80`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidColor(s.to_string());
+
+        let hex = s.trim();
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+
+        if !hex.is_ascii() {
+            return Err(invalid());
+        }
+
+        let digit = |i: usize, len: usize| u8::from_str_radix(&hex[i..i + len], 16);
+
+        let channels = match hex.len() {
+            3 => (digit(0, 1), digit(1, 1), digit(2, 1)),
+            6 => (digit(0, 2), digit(2, 2), digit(4, 2)),
+            _ => return Err(invalid()),
+        };
+
+        match channels {
+            (Ok(r), Ok(g), Ok(b)) if hex.len() == 3 => Ok(Self::new(r * 17, g * 17, b * 17)),
+            (Ok(r), Ok(g), Ok(b)) => Ok(Self::new(r, g, b)),
+            _ => Err(invalid()),
+        }
+    }
+}
+
 impl fmt::Display for Rgb24 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:>3} {:>3} {:>3}", self.r(), self.g(), self.b())
@@ -211,6 +356,107 @@ impl Hsv {
     }
 }
 
+/// CIE L*a*b* representation.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Lab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+impl Lab {
+    /// Finds the CIE76 color difference (Delta E) to another color.
+    pub fn delta_e(&self, other: &Self) -> f32 {
+        ((self.l - other.l).powi(2) + (self.a - other.a).powi(2) + (self.b - other.b).powi(2))
+            .sqrt()
+    }
+}
+
+/// Oklab representation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Oklab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+impl Oklab {
+    /// Finds the chroma, the distance from the neutral axis.
+    pub fn chroma(&self) -> f32 {
+        self.a.hypot(self.b)
+    }
+
+    /// Finds the hue angle in degrees, in the range [0, 360).
+    pub fn hue(&self) -> f32 {
+        self.b.atan2(self.a).to_degrees().rem_euclid(360.0)
+    }
+
+    /// Finds the Euclidean distance to another color.
+    pub fn distance(&self, other: &Self) -> f32 {
+        ((self.l - other.l).powi(2) + (self.a - other.a).powi(2) + (self.b - other.b).powi(2))
+            .sqrt()
+    }
+
+    /// Creates a color from its lightness, chroma and hue in degrees.
+    pub fn from_lch(l: f32, chroma: f32, hue: f32) -> Self {
+        let (sin, cos) = hue.to_radians().sin_cos();
+        Self {
+            l,
+            a: chroma * cos,
+            b: chroma * sin,
+        }
+    }
+
+    /// Checks whether the color can be represented in sRGB.
+    pub fn in_gamut(&self) -> bool {
+        const EPSILON: f32 = 1e-4;
+        self.linear_srgb()
+            .iter()
+            .all(|c| (-EPSILON..=1.0 + EPSILON).contains(c))
+    }
+
+    /// Creates the corresponding RGB24 representation, clipping colors
+    /// outside the sRGB gamut.
+    pub fn to_rgb24(&self) -> Rgb24 {
+        let [r, g, b] = self.linear_srgb().map(linear_to_srgb);
+        Rgb24::new(r, g, b)
+    }
+
+    /// Converts back to linear sRGB, without clipping.
+    fn linear_srgb(&self) -> [f32; 3] {
+        let l = (self.l + 0.3963378 * self.a + 0.2158038 * self.b).powi(3);
+        let m = (self.l - 0.1055613 * self.a - 0.0638542 * self.b).powi(3);
+        let s = (self.l - 0.0894842 * self.a - 1.2914855 * self.b).powi(3);
+
+        [
+            4.0767417 * l - 3.3077116 * m + 0.2309699 * s,
+            -1.268438 * l + 2.6097574 * m - 0.3413194 * s,
+            -0.0041961 * l - 0.7034186 * m + 1.7076147 * s,
+        ]
+    }
+}
+
+/// Converts an sRGB channel to linear light in the range [0, 1].
+pub(crate) fn srgb_to_linear(c: u8) -> f32 {
+    let c = c as f32 / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Converts linear light to an sRGB channel, clamping out-of-gamut values.
+pub(crate) fn linear_to_srgb(c: f32) -> u8 {
+    let c = c.clamp(0.0, 1.0);
+    let c = if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+    (c * 255.0).round() as u8
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -295,6 +541,20 @@ mod test {
         assert_eq!(Rgb24::average(&colors), Rgb24::new(114, 127, 126));
     }
 
+    #[test]
+    fn color_weighted_average() {
+        let colors = vec![
+            (Rgb24::new(216, 126, 83), 1),
+            (Rgb24::new(87, 73, 32), 3),
+            (Rgb24::new(48, 84, 50), 2),
+        ];
+
+        assert_eq!(Rgb24::weighted_average(&colors), Rgb24::new(96, 86, 47));
+
+        let colors = vec![(Rgb24::new(80, 92, 233), 7)];
+        assert_eq!(Rgb24::weighted_average(&colors), Rgb24::new(80, 92, 233));
+    }
+
     #[test]
     fn color_make_hsv() {
         let color = Rgb24::new(2, 117, 186);
@@ -334,4 +594,78 @@ mod test {
         assert_eq!(color.level_index(6), 0);
         assert_eq!(color.level_index(7), 7);
     }
+
+    #[test]
+    fn color_from_str() {
+        assert_eq!("#FF8000".parse::<Rgb24>().unwrap(), Rgb24::new(255, 128, 0));
+        assert_eq!("0a0b0c".parse::<Rgb24>().unwrap(), Rgb24::new(10, 11, 12));
+        assert_eq!("#F80".parse::<Rgb24>().unwrap(), Rgb24::new(255, 136, 0));
+
+        assert!("#FF80".parse::<Rgb24>().is_err());
+        assert!("#GG8000".parse::<Rgb24>().is_err());
+        assert!("#ÿÿÿ".parse::<Rgb24>().is_err());
+    }
+
+    #[test]
+    fn color_to_lab() {
+        let lab = Rgb24::new(255, 255, 255).to_lab();
+        assert!((lab.l - 100.0).abs() < 0.01);
+        assert!(lab.a.abs() < 0.01 && lab.b.abs() < 0.01);
+
+        let lab = Rgb24::new(255, 0, 0).to_lab();
+        assert!((lab.l - 53.24).abs() < 0.05);
+        assert!((lab.a - 80.09).abs() < 0.05);
+        assert!((lab.b - 67.20).abs() < 0.05);
+
+        let black = Rgb24::new(0, 0, 0);
+        let white = Rgb24::new(255, 255, 255);
+        assert!((black.delta_e(&white) - 100.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn color_relative_luminance() {
+        assert_eq!(Rgb24::new(0, 0, 0).relative_luminance(), 0.0);
+        assert!((Rgb24::new(255, 255, 255).relative_luminance() - 1.0).abs() < 0.001);
+        assert!((Rgb24::new(0, 255, 0).relative_luminance() - 0.7152).abs() < 0.001);
+
+        assert_eq!(
+            Rgb24::new(20, 20, 60).contrasting(),
+            Rgb24::new(255, 255, 255)
+        );
+        assert_eq!(Rgb24::new(255, 220, 0).contrasting(), Rgb24::new(0, 0, 0));
+
+        let (black, white) = (Rgb24::new(0, 0, 0), Rgb24::new(255, 255, 255));
+        assert!((black.contrast_ratio(&white) - 21.0).abs() < 0.01);
+        assert_eq!(white.contrast_ratio(&black), black.contrast_ratio(&white));
+        assert_eq!(white.contrast_ratio(&white), 1.0);
+    }
+
+    #[test]
+    fn color_to_oklab() {
+        let lab = Rgb24::new(255, 255, 255).to_oklab();
+        assert!((lab.l - 1.0).abs() < 0.001);
+        assert!(lab.chroma() < 0.001);
+
+        let lab = Rgb24::new(255, 0, 0).to_oklab();
+        assert!((lab.l - 0.628).abs() < 0.001);
+        assert!((lab.chroma() - 0.258).abs() < 0.001);
+        assert!((lab.hue() - 29.2).abs() < 0.1);
+
+        for color in [
+            Rgb24::new(255, 0, 0),
+            Rgb24::new(12, 200, 90),
+            Rgb24::new(3, 4, 5),
+        ] {
+            assert_eq!(color.to_oklab().to_rgb24(), color);
+            assert!(color.to_oklab().in_gamut());
+        }
+
+        let lch = Oklab::from_lch(lab.l, lab.chroma(), lab.hue());
+        assert!(lch.distance(&lab) < 0.001);
+        assert!(!Oklab::from_lch(0.9, 0.3, 264.0).in_gamut());
+
+        let black = Rgb24::new(0, 0, 0).to_oklab();
+        assert_eq!(black.l, 0.0);
+        assert!((black.distance(&Rgb24::new(255, 255, 255).to_oklab()) - 1.0).abs() < 0.001);
+    }
 }
@@ -0,0 +1,174 @@
+use crate::color::Rgb24;
+
+/// WCAG 2.x conformance level of a text and background pair.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum Level {
+    /// AA for large text, at least 18pt or 14pt bold.
+    AaLarge,
+    /// AA for normal text, or AAA for large text.
+    Aa,
+    /// AAA for normal text.
+    Aaa,
+}
+
+impl Level {
+    /// Every level, from the least to the most demanding.
+    pub const ALL: [Level; 3] = [Level::AaLarge, Level::Aa, Level::Aaa];
+
+    /// Gets the minimum contrast ratio of the level.
+    pub fn min_ratio(self) -> f32 {
+        match self {
+            Level::AaLarge => 3.0,
+            Level::Aa => 4.5,
+            Level::Aaa => 7.0,
+        }
+    }
+
+    /// Finds the most demanding level reached by a contrast ratio.
+    pub fn from_ratio(ratio: f32) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .rev()
+            .find(|level| ratio >= level.min_ratio())
+    }
+}
+
+/// Contrast of a text color on a background color of a palette.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pair {
+    /// Index of the text color in the palette.
+    pub text: usize,
+    /// Index of the background color in the palette.
+    pub background: usize,
+    /// WCAG 2.x contrast ratio, from 1 to 21.
+    pub ratio: f32,
+    /// APCA lightness contrast, negative for light text on a dark background.
+    pub lc: f32,
+}
+
+impl Pair {
+    /// Finds the most demanding WCAG level the pair passes, if any.
+    pub fn level(&self) -> Option<Level> {
+        Level::from_ratio(self.ratio)
+    }
+
+    /// Checks whether the pair passes a WCAG level.
+    pub fn passes(&self, level: Level) -> bool {
+        self.ratio >= level.min_ratio()
+    }
+}
+
+/// Measures the contrast of every ordered pair of distinct palette entries.
+///
+/// Pairs are listed by text color, then by background color. Both
+/// orientations of each pair are included, since APCA depends on which
+/// color is the text.
+///
+pub fn pairs(palette: &[Rgb24]) -> Vec<Pair> {
+    let indices = 0..palette.len();
+
+    indices
+        .clone()
+        .flat_map(|text| indices.clone().map(move |background| (text, background)))
+        .filter(|(text, background)| text != background)
+        .map(|(text, background)| Pair {
+            text,
+            background,
+            ratio: palette[text].contrast_ratio(&palette[background]),
+            lc: apca(&palette[text], &palette[background]),
+        })
+        .collect()
+}
+
+/// Computes the APCA lightness contrast (Lc) of text on a background.
+///
+/// This follows APCA-W3 0.0.98G-4g. Values range from about 106 for black
+/// text on white to about -108 for white text on black, and are 0 for pairs
+/// too close to be read.
+///
+pub fn apca(text: &Rgb24, background: &Rgb24) -> f32 {
+    let text = apca_luminance(text);
+    let background = apca_luminance(background);
+
+    if (background - text).abs() < 0.0005 {
+        return 0.0;
+    }
+
+    let lc = if background > text {
+        // Dark text on a light background.
+        let sapc = (background.powf(0.56) - text.powf(0.57)) * 1.14;
+        if sapc < 0.1 {
+            0.0
+        } else {
+            sapc - 0.027
+        }
+    } else {
+        // Light text on a dark background.
+        let sapc = (background.powf(0.65) - text.powf(0.62)) * 1.14;
+        if sapc > -0.1 {
+            0.0
+        } else {
+            sapc + 0.027
+        }
+    };
+
+    lc * 100.0
+}
+
+/// Estimates the screen luminance of a color for APCA, with a soft clamp
+/// of near-black colors.
+fn apca_luminance(color: &Rgb24) -> f32 {
+    let linear = |c: u8| (c as f32 / 255.0).powf(2.4);
+    let y = 0.2126729 * linear(color.r())
+        + 0.7151522 * linear(color.g())
+        + 0.072175 * linear(color.b());
+
+    if y < 0.022 {
+        y + (0.022 - y).powf(1.414)
+    } else {
+        y
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const BLACK: Rgb24 = Rgb24::new(0, 0, 0);
+    const WHITE: Rgb24 = Rgb24::new(255, 255, 255);
+    const GRAY: Rgb24 = Rgb24::new(136, 136, 136);
+
+    #[test]
+    fn contrast_apca() {
+        assert!((apca(&BLACK, &WHITE) - 106.04).abs() < 0.1);
+        assert!((apca(&WHITE, &BLACK) + 107.88).abs() < 0.1);
+        assert!((apca(&GRAY, &WHITE) - 63.06).abs() < 0.1);
+        assert!((apca(&WHITE, &GRAY) + 68.54).abs() < 0.1);
+        assert_eq!(apca(&GRAY, &GRAY), 0.0);
+    }
+
+    #[test]
+    fn contrast_levels() {
+        assert_eq!(Level::from_ratio(21.0), Some(Level::Aaa));
+        assert_eq!(Level::from_ratio(4.5), Some(Level::Aa));
+        assert_eq!(Level::from_ratio(4.49), Some(Level::AaLarge));
+        assert_eq!(Level::from_ratio(2.9), None);
+    }
+
+    #[test]
+    fn contrast_pairs() {
+        let pairs = pairs(&[BLACK, WHITE, GRAY]);
+        assert_eq!(pairs.len(), 6);
+        assert_eq!((pairs[0].text, pairs[0].background), (0, 1));
+        assert_eq!((pairs[5].text, pairs[5].background), (2, 1));
+
+        // Black on white passes everything, gray on white only large AA.
+        assert!(pairs[0].passes(Level::Aaa));
+        assert_eq!(pairs[5].level(), Some(Level::AaLarge));
+        assert!(!pairs[5].passes(Level::Aa));
+        assert!(pairs[0].lc > 0.0 && pairs[2].lc < 0.0);
+
+        assert!(super::pairs(&[BLACK]).is_empty());
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+
+use crate::color::{linear_to_srgb, srgb_to_linear, Rgb24};
+
+/// Color vision deficiency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum Deficiency {
+    /// No long-wavelength (red) cones.
+    Protanopia,
+    /// No medium-wavelength (green) cones.
+    Deuteranopia,
+    /// No short-wavelength (blue) cones.
+    Tritanopia,
+}
+
+impl Deficiency {
+    /// Every deficiency.
+    pub const ALL: [Deficiency; 3] = [
+        Deficiency::Protanopia,
+        Deficiency::Deuteranopia,
+        Deficiency::Tritanopia,
+    ];
+
+    /// Gets the Machado et al. (2009) simulation matrix at full severity,
+    /// applied to linear RGB.
+    fn matrix(self) -> [[f32; 3]; 3] {
+        match self {
+            Deficiency::Protanopia => [
+                [0.152286, 1.052583, -0.204868],
+                [0.114503, 0.786281, 0.099216],
+                [-0.003882, -0.048116, 1.051998],
+            ],
+            Deficiency::Deuteranopia => [
+                [0.367322, 0.860646, -0.227968],
+                [0.280085, 0.672501, 0.047413],
+                [-0.01182, 0.04294, 0.968881],
+            ],
+            Deficiency::Tritanopia => [
+                [1.255528, -0.076749, -0.178779],
+                [-0.078411, 0.930809, 0.147602],
+                [0.004733, 0.691367, 0.3039],
+            ],
+        }
+    }
+
+    /// Simulates how a color appears with the deficiency.
+    pub fn simulate(self, color: &Rgb24) -> Rgb24 {
+        let linear = [color.r(), color.g(), color.b()].map(srgb_to_linear);
+        let [r, g, b] = self.matrix().map(|row| {
+            let c = row.iter().zip(linear).map(|(m, c)| m * c).sum();
+            linear_to_srgb(c)
+        });
+
+        Rgb24::new(r, g, b)
+    }
+}
+
+/// Default Delta E below which simulated colors are considered
+/// indistinguishable.
+pub const DEFAULT_THRESHOLD: f32 = 10.0;
+
+/// Simulates how colors appear with a deficiency.
+///
+/// Conversions are cached, so images with many repeated colors are cheap.
+///
+pub fn simulate(colors: &[Rgb24], deficiency: Deficiency) -> Vec<Rgb24> {
+    let mut cache = HashMap::new();
+
+    colors
+        .iter()
+        .map(|color| {
+            *cache
+                .entry(*color)
+                .or_insert_with(|| deficiency.simulate(color))
+        })
+        .collect()
+}
+
+/// Simulates how an image appears with a deficiency.
+/// The alpha channel is preserved.
+#[cfg(feature = "image")]
+pub fn simulate_image(img: &image::RgbaImage, deficiency: Deficiency) -> image::RgbaImage {
+    let colors = crate::rgba_image_to_rgb24(img, u8::MIN);
+    let simulated = simulate(&colors, deficiency);
+
+    let mut out = img.clone();
+    for (px, color) in out.pixels_mut().zip(simulated) {
+        px.0 = [color.r(), color.g(), color.b(), px.0[3]];
+    }
+
+    out
+}
+
+/// Pair of palette colors that look alike with a deficiency.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Confusion {
+    /// Indices of the colors in the palette.
+    pub first: usize,
+    pub second: usize,
+    /// CIE76 Delta E between the simulated colors.
+    pub delta_e: f32,
+}
+
+/// Finds the palette colors that become indistinguishable with a
+/// deficiency, that is whose simulated colors are closer than `threshold`
+/// (CIE76 Delta E). Pairs that already look alike without the deficiency
+/// are left out.
+pub fn confusions(palette: &[Rgb24], deficiency: Deficiency, threshold: f32) -> Vec<Confusion> {
+    let labs: Vec<_> = palette.iter().map(Rgb24::to_lab).collect();
+    let simulated: Vec<_> = simulate(palette, deficiency)
+        .iter()
+        .map(Rgb24::to_lab)
+        .collect();
+
+    let mut confusions = Vec::new();
+
+    for first in 0..palette.len() {
+        for second in first + 1..palette.len() {
+            let delta_e = simulated[first].delta_e(&simulated[second]);
+            if delta_e < threshold && labs[first].delta_e(&labs[second]) >= threshold {
+                confusions.push(Confusion {
+                    first,
+                    second,
+                    delta_e,
+                });
+            }
+        }
+    }
+
+    confusions
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn cvd_simulate() {
+        // Grays are unaffected.
+        for deficiency in Deficiency::ALL {
+            for gray in [0, 128, 255] {
+                let color = Rgb24::new(gray, gray, gray);
+                assert!(deficiency.simulate(&color).delta_e(&color) < 1.0);
+            }
+        }
+
+        // Red loses most of its brightness without red cones.
+        let red = Deficiency::Protanopia.simulate(&Rgb24::new(255, 0, 0));
+        assert!(red.r() < 150 && red.relative_luminance() < 0.2);
+
+        let colors = [Rgb24::new(255, 0, 0), Rgb24::new(255, 0, 0)];
+        let simulated = simulate(&colors, Deficiency::Deuteranopia);
+        assert_eq!(simulated[0], Deficiency::Deuteranopia.simulate(&colors[0]));
+        assert_eq!(simulated[0], simulated[1]);
+    }
+
+    #[test]
+    fn cvd_confusions() {
+        // Red and green collapse with deuteranopia, but not with tritanopia.
+        let palette = [
+            Rgb24::new(200, 80, 40),
+            Rgb24::new(120, 120, 40),
+            Rgb24::new(40, 40, 200),
+        ];
+
+        let confusions = confusions(&palette, Deficiency::Deuteranopia, DEFAULT_THRESHOLD);
+        assert_eq!(confusions.len(), 1);
+        assert_eq!((confusions[0].first, confusions[0].second), (0, 1));
+        assert!(confusions[0].delta_e < DEFAULT_THRESHOLD);
+
+        assert!(super::confusions(&palette, Deficiency::Tritanopia, DEFAULT_THRESHOLD).is_empty());
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn cvd_image() {
+        let img = image::RgbaImage::from_pixel(2, 1, image::Rgba([255, 0, 0, 100]));
+        let simulated = simulate_image(&img, Deficiency::Protanopia);
+
+        let red = Deficiency::Protanopia.simulate(&Rgb24::new(255, 0, 0));
+        assert_eq!(
+            simulated.get_pixel(1, 0).0,
+            [red.r(), red.g(), red.b(), 100]
+        );
+    }
+}
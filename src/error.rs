@@ -0,0 +1,80 @@
+use std::fmt;
+use std::io;
+
+/// Result type used throughout paletter.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Errors produced while reading images and quantizing palettes.
+///
+/// Variants may be added in minor releases, and depend on the enabled
+/// features, so matches need a wildcard arm.
+///
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// Reading the input failed.
+    Io(io::Error),
+    /// The image data could not be decoded.
+    #[cfg(feature = "image")]
+    Decode(image::ImageError),
+    /// There are no colors to quantize.
+    EmptyInput,
+    /// The requested palette size cannot be produced.
+    InvalidSize(usize),
+    /// The image format is not supported.
+    UnsupportedFormat(String),
+    /// A raw pixel buffer does not match its declared layout.
+    InvalidBuffer(String),
+    /// A color could not be parsed.
+    InvalidColor(String),
+    /// A palette file could not be parsed.
+    InvalidPalette(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(err) => match err.kind() {
+                io::ErrorKind::NotFound => write!(f, "file not found"),
+                io::ErrorKind::PermissionDenied => write!(f, "permission denied"),
+                _ => write!(f, "I/O error: {err}"),
+            },
+            #[cfg(feature = "image")]
+            Error::Decode(err) => write!(f, "decode error: {err}"),
+            Error::EmptyInput => write!(f, "no colors to quantize"),
+            Error::InvalidSize(size) => write!(f, "invalid palette size: {size}"),
+            Error::UnsupportedFormat(format) => write!(f, "unsupported format: {format}"),
+            Error::InvalidBuffer(reason) => write!(f, "invalid pixel buffer: {reason}"),
+            Error::InvalidColor(color) => write!(f, "invalid color: {color}"),
+            Error::InvalidPalette(reason) => write!(f, "invalid palette: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            #[cfg(feature = "image")]
+            Error::Decode(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+#[cfg(feature = "image")]
+impl From<image::ImageError> for Error {
+    fn from(err: image::ImageError) -> Self {
+        match err {
+            image::ImageError::IoError(err) => Error::Io(err),
+            image::ImageError::Unsupported(err) => Error::UnsupportedFormat(err.to_string()),
+            err => Error::Decode(err),
+        }
+    }
+}
@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use crate::color::{Lab, Rgb24};
+use crate::error::Error;
+
+/// Color range removed from the input before quantization.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Exclusion {
+    color: Rgb24,
+    lab: Lab,
+    tolerance: f32,
+}
+
+impl Exclusion {
+    /// Default distance within which pixels are removed.
+    pub const DEFAULT_TOLERANCE: f32 = 10.0;
+
+    /// Creates an exclusion of the colors within `tolerance` (CIE76 Delta E)
+    /// of `color`.
+    pub fn new(color: Rgb24, tolerance: f32) -> Self {
+        Self {
+            color,
+            lab: color.to_lab(),
+            tolerance,
+        }
+    }
+
+    /// Retrieves the excluded color.
+    pub fn color(&self) -> Rgb24 {
+        self.color
+    }
+
+    /// Retrieves the distance within which pixels are removed.
+    pub fn tolerance(&self) -> f32 {
+        self.tolerance
+    }
+
+    /// Checks whether a color is excluded.
+    pub fn matches(&self, color: &Rgb24) -> bool {
+        color.to_lab().delta_e(&self.lab) <= self.tolerance
+    }
+}
+
+impl FromStr for Exclusion {
+    type Err = Error;
+
+    /// Parses a color with an optional tolerance, such as `#FFFFFF~10`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (color, tolerance) = match s.split_once('~') {
+            Some((color, tolerance)) => {
+                let tolerance = tolerance
+                    .trim()
+                    .parse::<f32>()
+                    .ok()
+                    .filter(|t| t.is_finite() && *t >= 0.0)
+                    .ok_or_else(|| Error::InvalidColor(s.to_string()))?;
+                (color, tolerance)
+            }
+            None => (s, Self::DEFAULT_TOLERANCE),
+        };
+
+        Ok(Self::new(color.trim().parse()?, tolerance))
+    }
+}
+
+/// Removes the colors matching any exclusion.
+pub fn exclude(colors: &[Rgb24], exclusions: &[Exclusion]) -> Vec<Rgb24> {
+    exclude_by(colors, exclusions, |color| *color)
+}
+
+/// Removes the weighted pixels whose color matches any exclusion.
+pub fn exclude_pixels(pixels: &[(Rgb24, u64)], exclusions: &[Exclusion]) -> Vec<(Rgb24, u64)> {
+    exclude_by(pixels, exclusions, |(color, _)| *color)
+}
+
+/// Removes the items whose color matches any exclusion.
+fn exclude_by<T: Copy>(
+    items: &[T],
+    exclusions: &[Exclusion],
+    color: impl Fn(&T) -> Rgb24,
+) -> Vec<T> {
+    if exclusions.is_empty() {
+        return items.to_vec();
+    }
+
+    let mut cache = HashMap::new();
+
+    items
+        .iter()
+        .filter(|item| {
+            let color = color(item);
+            !*cache
+                .entry(color)
+                .or_insert_with(|| exclusions.iter().any(|e| e.matches(&color)))
+        })
+        .copied()
+        .collect()
+}
+
+/// Share of border pixels that must match for a solid background.
+const BACKGROUND_SHARE: f64 = 0.5;
+
+/// Detects a solid background color from the border of an image.
+///
+/// `pixels` are the `width * height` pixels of the image in row-major
+/// order. Border pixels are grouped by coarse color, and the average of the
+/// largest group is returned if at least half of the border lies within
+/// `tolerance` of it.
+///
+pub fn border_background(
+    pixels: &[Rgb24],
+    width: usize,
+    height: usize,
+    tolerance: f32,
+) -> Option<Rgb24> {
+    if width == 0 || height == 0 || pixels.len() < width * height {
+        return None;
+    }
+
+    let border: Vec<_> = (0..height)
+        .flat_map(|y| {
+            let xs: Vec<_> = match y == 0 || y == height - 1 {
+                true => (0..width).collect(),
+                false => vec![0, width - 1],
+            };
+            xs.into_iter().map(move |x| pixels[y * width + x])
+        })
+        .collect();
+
+    // Group by the top four bits of each channel to absorb noise.
+    let mut groups: HashMap<_, Vec<Rgb24>> = HashMap::new();
+    for color in &border {
+        let key = (color.r() >> 4, color.g() >> 4, color.b() >> 4);
+        groups.entry(key).or_default().push(*color);
+    }
+
+    let largest = groups
+        .into_iter()
+        .max_by_key(|(key, colors)| (colors.len(), *key))?
+        .1;
+
+    let background = Rgb24::average(&largest);
+    let exclusion = Exclusion::new(background, tolerance);
+    let matching = border.iter().filter(|c| exclusion.matches(c)).count();
+
+    (matching as f64 >= BACKGROUND_SHARE * border.len() as f64).then_some(background)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn filter_exclusion() {
+        let white: Exclusion = "#FFFFFF~5".parse().unwrap();
+        assert_eq!(white.color(), Rgb24::new(255, 255, 255));
+        assert_eq!(white.tolerance(), 5.0);
+        assert_eq!(
+            "F00".parse::<Exclusion>().unwrap().tolerance(),
+            Exclusion::DEFAULT_TOLERANCE
+        );
+        assert!("#FFFFFF~".parse::<Exclusion>().is_err());
+        assert!("#FFFFFF~-1".parse::<Exclusion>().is_err());
+        assert!("white~1".parse::<Exclusion>().is_err());
+
+        let colors = vec![
+            Rgb24::new(255, 255, 255),
+            Rgb24::new(250, 251, 250),
+            Rgb24::new(200, 200, 200),
+            Rgb24::new(255, 0, 0),
+        ];
+        assert_eq!(exclude(&colors, &[white]), colors[2..]);
+        assert_eq!(exclude(&colors, &[]), colors);
+    }
+
+    #[test]
+    fn filter_border_background() {
+        let white = Rgb24::new(250, 250, 250);
+        let red = Rgb24::new(200, 0, 0);
+
+        // A red square on a 5x5 white background with a noisy pixel.
+        let mut pixels = vec![white; 25];
+        pixels[1] = Rgb24::new(255, 255, 255);
+        for i in [6, 7, 8, 11, 12, 13, 16, 17, 18] {
+            pixels[i] = red;
+        }
+
+        let background = border_background(&pixels, 5, 5, 10.0).unwrap();
+        assert!(background.delta_e(&white) < 1.0);
+
+        // No solid border.
+        let blue = Rgb24::new(0, 0, 200);
+        let stripes: Vec<_> = (0..25).map(|i| [white, red, blue][i % 3]).collect();
+        assert_eq!(border_background(&stripes, 5, 5, 10.0), None);
+        assert_eq!(border_background(&[], 0, 0, 10.0), None);
+    }
+}
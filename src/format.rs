@@ -0,0 +1,358 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use crate::color::Rgb24;
+use crate::error::{Error, Result};
+
+/// Palette file format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum PaletteFormat {
+    /// One hexadecimal color per line.
+    Hex,
+    /// GIMP palette.
+    Gpl,
+    /// JASC (Paint Shop Pro) palette.
+    Pal,
+    /// CSS custom properties on `:root`.
+    Css,
+    /// SCSS variables.
+    Scss,
+    /// Tailwind configuration with a `theme.colors` object.
+    Tailwind,
+    /// W3C Design Tokens JSON.
+    Tokens,
+}
+
+impl PaletteFormat {
+    /// Guesses the palette format from a file extension.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Option<Self> {
+        let ext = path.as_ref().extension()?.to_str()?.to_ascii_lowercase();
+
+        match ext.as_str() {
+            "hex" | "txt" => Some(PaletteFormat::Hex),
+            "gpl" => Some(PaletteFormat::Gpl),
+            "pal" => Some(PaletteFormat::Pal),
+            "css" => Some(PaletteFormat::Css),
+            "scss" => Some(PaletteFormat::Scss),
+            "js" | "cjs" => Some(PaletteFormat::Tailwind),
+            "json" => Some(PaletteFormat::Tokens),
+            _ => None,
+        }
+    }
+
+    /// Parses a palette.
+    ///
+    /// Colors are read back from code formats by taking the first
+    /// `#RRGGBB` value of each line.
+    ///
+    pub fn parse(self, text: &str) -> Result<Vec<Rgb24>> {
+        match self {
+            PaletteFormat::Hex => parse_hex(text),
+            PaletteFormat::Gpl => parse_gpl(text),
+            PaletteFormat::Pal => parse_pal(text),
+            PaletteFormat::Css
+            | PaletteFormat::Scss
+            | PaletteFormat::Tailwind
+            | PaletteFormat::Tokens => parse_code(text),
+        }
+    }
+
+    /// Writes a palette, naming colors `color-1`, `color-2` and so on.
+    pub fn write(self, palette: &[Rgb24]) -> String {
+        self.write_named::<&str>(palette, &[])
+    }
+
+    /// Writes a palette with color names.
+    ///
+    /// Names label GIMP palette entries and become kebab-case identifiers
+    /// in code formats, with a numeric suffix on duplicates. Colors without
+    /// a name keep their hexadecimal value as a GIMP label, and are numbered
+    /// by their position in the palette in code formats.
+    ///
+    pub fn write_named<S: AsRef<str>>(self, palette: &[Rgb24], names: &[S]) -> String {
+        let mut out = String::new();
+        let keys = keys(palette.len(), names);
+
+        match self {
+            PaletteFormat::Hex => {
+                for color in palette {
+                    let _ = writeln!(out, "{}", &color.to_hex_string()[1..]);
+                }
+            }
+            PaletteFormat::Gpl => {
+                let _ = writeln!(out, "GIMP Palette\nName: paletter\nColumns: 0\n#");
+                for (i, color) in palette.iter().enumerate() {
+                    let name = match names.get(i).map(|name| name.as_ref().trim()) {
+                        Some(name) if !name.is_empty() => name.to_string(),
+                        _ => color.to_hex_string(),
+                    };
+                    let _ = writeln!(out, "{color}\t{name}");
+                }
+            }
+            PaletteFormat::Pal => {
+                let _ = writeln!(out, "JASC-PAL\n0100\n{}", palette.len());
+                for color in palette {
+                    let _ = writeln!(out, "{} {} {}", color.r(), color.g(), color.b());
+                }
+            }
+            PaletteFormat::Css => {
+                let _ = writeln!(out, ":root {{");
+                for (color, key) in palette.iter().zip(&keys) {
+                    let _ = writeln!(out, "  --{key}: {};", color.to_hex_string());
+                }
+                let _ = writeln!(out, "}}");
+            }
+            PaletteFormat::Scss => {
+                for (color, key) in palette.iter().zip(&keys) {
+                    let _ = writeln!(out, "${key}: {};", color.to_hex_string());
+                }
+            }
+            PaletteFormat::Tailwind => {
+                let _ = writeln!(out, "module.exports = {{\n  theme: {{\n    colors: {{");
+                for (color, key) in palette.iter().zip(&keys) {
+                    let _ = writeln!(out, "      '{key}': '{}',", color.to_hex_string());
+                }
+                let _ = writeln!(out, "    }},\n  }},\n}};");
+            }
+            PaletteFormat::Tokens => {
+                let _ = writeln!(out, "{{\n  \"color\": {{");
+                for (i, (color, key)) in palette.iter().zip(&keys).enumerate() {
+                    let comma = if i + 1 < palette.len() { "," } else { "" };
+                    let _ = writeln!(
+                        out,
+                        "    \"{key}\": {{ \"$type\": \"color\", \"$value\": \"{}\" }}{comma}",
+                        color.to_hex_string()
+                    );
+                }
+                let _ = writeln!(out, "  }}\n}}");
+            }
+        }
+
+        out
+    }
+}
+
+/// Parses a list of hexadecimal colors. Blank lines and `;` comments are skipped.
+fn parse_hex(text: &str) -> Result<Vec<Rgb24>> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with(';'))
+        .map(str::parse)
+        .collect()
+}
+
+/// Parses a GIMP palette.
+fn parse_gpl(text: &str) -> Result<Vec<Rgb24>> {
+    let mut lines = text.lines().map(str::trim);
+
+    if lines.next() != Some("GIMP Palette") {
+        return Err(Error::InvalidPalette("missing GIMP Palette header".into()));
+    }
+
+    lines
+        .filter(|line| {
+            !line.is_empty()
+                && !line.starts_with('#')
+                && !line.starts_with("Name:")
+                && !line.starts_with("Columns:")
+        })
+        .map(|line| parse_rgb(line, true))
+        .collect()
+}
+
+/// Parses a JASC palette.
+fn parse_pal(text: &str) -> Result<Vec<Rgb24>> {
+    let mut lines = text.lines().map(str::trim).filter(|line| !line.is_empty());
+
+    if lines.next() != Some("JASC-PAL") {
+        return Err(Error::InvalidPalette("missing JASC-PAL header".into()));
+    }
+
+    // Version.
+    lines.next();
+
+    let count: usize = lines
+        .next()
+        .and_then(|line| line.parse().ok())
+        .ok_or_else(|| Error::InvalidPalette("missing color count".into()))?;
+
+    let colors = lines
+        .map(|line| parse_rgb(line, false))
+        .collect::<Result<Vec<_>>>()?;
+
+    if colors.len() != count {
+        return Err(Error::InvalidPalette(format!(
+            "expected {count} colors, found {}",
+            colors.len()
+        )));
+    }
+
+    Ok(colors)
+}
+
+/// Parses the first `#RRGGBB` value of each line of CSS, SCSS, JavaScript
+/// or JSON. Lines without one are skipped.
+fn parse_code(text: &str) -> Result<Vec<Rgb24>> {
+    text.lines()
+        .filter_map(|line| {
+            line.match_indices('#').find_map(|(i, _)| {
+                let digits = &line[i + 1..];
+                let len = digits
+                    .bytes()
+                    .take_while(|b| b.is_ascii_alphanumeric())
+                    .count();
+                (len == 6).then(|| digits[..6].parse())
+            })
+        })
+        .collect()
+}
+
+/// Makes unique kebab-case identifiers from color names.
+fn keys<S: AsRef<str>>(len: usize, names: &[S]) -> Vec<String> {
+    let mut keys: Vec<String> = Vec::with_capacity(len);
+
+    for i in 0..len {
+        let name = names.get(i).map(|name| name.as_ref()).unwrap_or_default();
+        let mut base = String::new();
+        for c in name.chars() {
+            if c.is_ascii_alphanumeric() {
+                base.push(c.to_ascii_lowercase());
+            } else if !base.is_empty() && !base.ends_with('-') {
+                base.push('-');
+            }
+        }
+        let base = match base.trim_end_matches('-') {
+            "" => format!("color-{}", i + 1),
+            // Identifiers can't start with a digit.
+            base if base.starts_with(|c: char| c.is_ascii_digit()) => format!("color-{base}"),
+            base => base.to_string(),
+        };
+
+        let mut key = base.clone();
+        let mut n = 2;
+        while keys.contains(&key) {
+            key = format!("{base}-{n}");
+            n += 1;
+        }
+        keys.push(key);
+    }
+
+    keys
+}
+
+/// Parses a line of whitespace-separated decimal channels.
+/// If `named`, anything after the channels is ignored.
+fn parse_rgb(line: &str, named: bool) -> Result<Rgb24> {
+    let invalid = || Error::InvalidPalette(format!("invalid color entry: {line}"));
+
+    let mut fields = line.split_whitespace();
+    let mut channel = || -> Result<u8> {
+        fields
+            .next()
+            .and_then(|f| f.parse().ok())
+            .ok_or_else(invalid)
+    };
+
+    let color = Rgb24::new(channel()?, channel()?, channel()?);
+
+    if !named && fields.next().is_some() {
+        return Err(invalid());
+    }
+
+    Ok(color)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn palette() -> Vec<Rgb24> {
+        vec![
+            Rgb24::new(0, 0, 0),
+            Rgb24::new(255, 128, 0),
+            Rgb24::new(12, 200, 34),
+        ]
+    }
+
+    #[test]
+    fn format_round_trip() {
+        for format in [
+            PaletteFormat::Hex,
+            PaletteFormat::Gpl,
+            PaletteFormat::Pal,
+            PaletteFormat::Css,
+            PaletteFormat::Scss,
+            PaletteFormat::Tailwind,
+            PaletteFormat::Tokens,
+        ] {
+            let text = format.write(&palette());
+            assert_eq!(format.parse(&text).unwrap(), palette());
+        }
+    }
+
+    #[test]
+    fn format_parse() {
+        let text = "; lospec\nff8000\n\n#0C0C0C\n";
+        assert_eq!(
+            PaletteFormat::Hex.parse(text).unwrap(),
+            vec![Rgb24::new(255, 128, 0), Rgb24::new(12, 12, 12)]
+        );
+
+        let text = "GIMP Palette\nName: Test\nColumns: 4\n# comment\n  0   0   0\tBlack\n255 255 255 White\n";
+        assert_eq!(
+            PaletteFormat::Gpl.parse(text).unwrap(),
+            vec![Rgb24::new(0, 0, 0), Rgb24::new(255, 255, 255)]
+        );
+
+        assert!(PaletteFormat::Pal
+            .parse("JASC-PAL\n0100\n2\n0 0 0\n")
+            .is_err());
+        assert!(PaletteFormat::Gpl.parse("0 0 0\n").is_err());
+    }
+
+    #[test]
+    fn format_from_path() {
+        assert_eq!(
+            PaletteFormat::from_path("a/b.GPL"),
+            Some(PaletteFormat::Gpl)
+        );
+        assert_eq!(PaletteFormat::from_path("b.hex"), Some(PaletteFormat::Hex));
+        assert_eq!(
+            PaletteFormat::from_path("theme.tokens.json"),
+            Some(PaletteFormat::Tokens)
+        );
+        assert_eq!(PaletteFormat::from_path("b.png"), None);
+    }
+
+    #[test]
+    fn format_code() {
+        let css = PaletteFormat::Css.write(&palette());
+        assert!(css.starts_with(":root {\n  --color-1: #000000;\n"));
+
+        let names = ["Orange", "Lime Green!", "orange"];
+        let scss = PaletteFormat::Scss.write_named(&palette(), &names);
+        assert_eq!(
+            scss,
+            "$orange: #000000;\n$lime-green: #FF8000;\n$orange-2: #0CC822;\n"
+        );
+
+        let tailwind = PaletteFormat::Tailwind.write_named(&palette(), &["", "1st"]);
+        assert!(tailwind.contains("'color-1': '#000000',"));
+        assert!(tailwind.contains("'color-1st': '#FF8000',"));
+        assert!(tailwind.contains("'color-3': '#0CC822',"));
+
+        let tokens = PaletteFormat::Tokens.write(&palette());
+        assert!(tokens.contains(r##""color-3": { "$type": "color", "$value": "#0CC822" }"##));
+        assert!(!tokens.contains("},\n  }"));
+
+        let gpl = PaletteFormat::Gpl.write_named(&palette(), &names);
+        assert!(gpl.contains("255 128   0\tLime Green!"));
+
+        // Unnamed entries keep their hex value as a name.
+        let gpl = PaletteFormat::Gpl.write_named(&palette(), &["", " "]);
+        assert!(gpl.contains("  0   0   0\t#000000"));
+        assert!(gpl.contains("255 128   0\t#FF8000"));
+        assert!(gpl.contains(" 12 200  34\t#0CC822"));
+    }
+}
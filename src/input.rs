@@ -0,0 +1,390 @@
+#[cfg(feature = "image")]
+use std::path::Path;
+
+use crate::color::Rgb24;
+use crate::error::{Error, Result};
+
+/// Memory layout of a raw 8-bit pixel buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PixelLayout {
+    Rgb8,
+    Rgba8,
+    Bgr8,
+    Bgra8,
+    Gray8,
+    GrayAlpha8,
+}
+
+impl PixelLayout {
+    /// Retrieves the number of bytes used by a single pixel.
+    pub fn bytes_per_pixel(self) -> usize {
+        match self {
+            PixelLayout::Rgb8 | PixelLayout::Bgr8 => 3,
+            PixelLayout::Rgba8 | PixelLayout::Bgra8 => 4,
+            PixelLayout::Gray8 => 1,
+            PixelLayout::GrayAlpha8 => 2,
+        }
+    }
+
+    /// Decodes a single pixel into a color and an alpha value.
+    fn decode(self, px: &[u8]) -> (Rgb24, u8) {
+        match self {
+            PixelLayout::Rgb8 => (Rgb24::new(px[0], px[1], px[2]), u8::MAX),
+            PixelLayout::Rgba8 => (Rgb24::new(px[0], px[1], px[2]), px[3]),
+            PixelLayout::Bgr8 => (Rgb24::new(px[2], px[1], px[0]), u8::MAX),
+            PixelLayout::Bgra8 => (Rgb24::new(px[2], px[1], px[0]), px[3]),
+            PixelLayout::Gray8 => (Rgb24::new(px[0], px[0], px[0]), u8::MAX),
+            PixelLayout::GrayAlpha8 => (Rgb24::new(px[0], px[0], px[0]), px[1]),
+        }
+    }
+}
+
+/// Mask value from which a pixel is selected. Masks are meant to be black
+/// and white, so anything closer to white counts.
+pub const MASK_THRESHOLD: u8 = 128;
+
+/// Reads a raw pixel buffer to an RGB24 buffer.
+///
+/// Rows are `stride` bytes apart, which must be at least `width` pixels
+/// wide. Padding at the end of each row is ignored. Pixels with an alpha
+/// value below `alpha_min` are skipped.
+///
+pub fn raw_to_rgb24(
+    buf: &[u8],
+    layout: PixelLayout,
+    width: usize,
+    height: usize,
+    stride: usize,
+    alpha_min: u8,
+) -> Result<Vec<Rgb24>> {
+    raw_masked_to_rgb24(buf, layout, width, height, stride, alpha_min, None)
+}
+
+/// Reads the selected pixels of a raw pixel buffer to an RGB24 buffer.
+///
+/// The mask holds one byte per pixel, `width` bytes per row without
+/// padding. Only pixels with a mask value of at least [`MASK_THRESHOLD`]
+/// are read. Without a mask, this is the same as [`raw_to_rgb24`].
+///
+pub fn raw_masked_to_rgb24(
+    buf: &[u8],
+    layout: PixelLayout,
+    width: usize,
+    height: usize,
+    stride: usize,
+    alpha_min: u8,
+    mask: Option<&[u8]>,
+) -> Result<Vec<Rgb24>> {
+    // Dimensions may come from untrusted input, so sizes are checked.
+    let too_large = || {
+        Error::InvalidBuffer(format!(
+            "{width}x{height} image with a stride of {stride} bytes is too large"
+        ))
+    };
+    let row_len = width
+        .checked_mul(layout.bytes_per_pixel())
+        .ok_or_else(too_large)?;
+
+    if stride < row_len {
+        return Err(Error::InvalidBuffer(format!(
+            "stride of {stride} bytes is shorter than a row of {row_len} bytes"
+        )));
+    }
+
+    let expected = match height {
+        0 => 0,
+        _ => stride
+            .checked_mul(height - 1)
+            .and_then(|len| len.checked_add(row_len))
+            .ok_or_else(too_large)?,
+    };
+
+    if buf.len() < expected {
+        return Err(Error::InvalidBuffer(format!(
+            "expected at least {expected} bytes, found {}",
+            buf.len()
+        )));
+    }
+
+    if let Some(mask) = mask {
+        check_mask(mask.len(), width, height)?;
+    }
+
+    if row_len == 0 {
+        return Ok(vec![]);
+    }
+
+    let colors = (0..height)
+        .flat_map(|y| buf[y * stride..y * stride + row_len].chunks_exact(layout.bytes_per_pixel()))
+        .enumerate()
+        .filter(|&(i, _)| mask.is_none_or(|mask| mask[i] >= MASK_THRESHOLD))
+        .map(|(_, px)| layout.decode(px))
+        .filter(|&(_, alpha)| alpha >= alpha_min)
+        .map(|(color, _)| color)
+        .collect();
+
+    Ok(colors)
+}
+
+/// Checks that a mask of `len` bytes covers an image.
+fn check_mask(len: usize, width: usize, height: usize) -> Result<()> {
+    if width.checked_mul(height).is_none_or(|pixels| len < pixels) {
+        return Err(Error::InvalidBuffer(format!(
+            "mask of {len} pixels is smaller than the {width}x{height} image"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Reads an image file to an RGB24 buffer.
+#[cfg(feature = "image")]
+pub fn img_to_rgb24<P: AsRef<Path>>(path: P, alpha_min: u8) -> Result<Vec<Rgb24>> {
+    let img = image::open(path)?;
+    Ok(dynamic_image_to_rgb24(&img, alpha_min))
+}
+
+/// Decodes an encoded image held in memory to an RGB24 buffer.
+///
+/// The image format is guessed from the leading bytes.
+///
+#[cfg(feature = "image")]
+pub fn bytes_to_rgb24(bytes: &[u8], alpha_min: u8) -> Result<Vec<Rgb24>> {
+    let img = image::load_from_memory(bytes)?;
+    Ok(dynamic_image_to_rgb24(&img, alpha_min))
+}
+
+/// Reads a decoded image to an RGB24 buffer.
+#[cfg(feature = "image")]
+pub fn dynamic_image_to_rgb24(img: &image::DynamicImage, alpha_min: u8) -> Vec<Rgb24> {
+    rgba_image_to_rgb24(&img.to_rgba8(), alpha_min)
+}
+
+/// Reads an RGBA image to an RGB24 buffer.
+#[cfg(feature = "image")]
+pub fn rgba_image_to_rgb24(img: &image::RgbaImage, alpha_min: u8) -> Vec<Rgb24> {
+    img.chunks_exact(4)
+        .filter(|c| c[3] >= alpha_min)
+        .map(|ch| Rgb24::new(ch[0], ch[1], ch[2]))
+        .collect()
+}
+
+/// Reads the pixels of an image selected by a grayscale mask of the same
+/// size to an RGB24 buffer. See [`raw_masked_to_rgb24`].
+#[cfg(feature = "image")]
+pub fn masked_image_to_rgb24(
+    img: &image::DynamicImage,
+    alpha_min: u8,
+    mask: Option<&image::GrayImage>,
+) -> Result<Vec<Rgb24>> {
+    check_image_mask(img, mask)?;
+
+    let img = img.to_rgba8();
+    let (width, height) = (img.width() as usize, img.height() as usize);
+    let mask = mask.map(|mask| mask.as_raw().as_slice());
+
+    raw_masked_to_rgb24(
+        &img,
+        PixelLayout::Rgba8,
+        width,
+        height,
+        width * 4,
+        alpha_min,
+        mask,
+    )
+}
+
+/// Reads the pixels of an image with their weights.
+///
+/// `weights` holds one weight per pixel in row-major order, for instance
+/// from [`Weighting::weights`](crate::weight::Weighting::weights). Pixels
+/// below `alpha_min`, outside the mask or with a zero weight are skipped.
+///
+#[cfg(feature = "image")]
+pub fn weighted_image_to_rgb24(
+    img: &image::DynamicImage,
+    alpha_min: u8,
+    mask: Option<&image::GrayImage>,
+    weights: &[u64],
+) -> Result<Vec<(Rgb24, u64)>> {
+    check_image_mask(img, mask)?;
+
+    let pixels = img.width() as usize * img.height() as usize;
+    if weights.len() != pixels {
+        return Err(Error::InvalidBuffer(format!(
+            "{} weights for {pixels} pixels",
+            weights.len()
+        )));
+    }
+
+    let img = img.to_rgba8();
+    let selected = |i: usize| mask.is_none_or(|mask| mask.as_raw()[i] >= MASK_THRESHOLD);
+
+    Ok(img
+        .chunks_exact(4)
+        .zip(weights)
+        .enumerate()
+        .filter(|(i, (ch, &weight))| ch[3] >= alpha_min && weight > 0 && selected(*i))
+        .map(|(_, (ch, &weight))| (Rgb24::new(ch[0], ch[1], ch[2]), weight))
+        .collect())
+}
+
+/// Checks that a mask has the same size as an image.
+#[cfg(feature = "image")]
+fn check_image_mask(img: &image::DynamicImage, mask: Option<&image::GrayImage>) -> Result<()> {
+    match mask {
+        Some(mask) if mask.dimensions() != (img.width(), img.height()) => {
+            Err(Error::InvalidBuffer(format!(
+                "mask is {}x{} but the image is {}x{}",
+                mask.width(),
+                mask.height(),
+                img.width(),
+                img.height()
+            )))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Reads an RGB image to an RGB24 buffer.
+#[cfg(feature = "image")]
+pub fn rgb_image_to_rgb24(img: &image::RgbImage) -> Vec<Rgb24> {
+    img.chunks_exact(3)
+        .map(|ch| Rgb24::new(ch[0], ch[1], ch[2]))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn raw_layouts() {
+        let buf = [10, 20, 30, 255, 40, 50, 60, 0];
+
+        let colors = raw_to_rgb24(&buf, PixelLayout::Rgba8, 2, 1, 8, 0).unwrap();
+        assert_eq!(colors, vec![Rgb24::new(10, 20, 30), Rgb24::new(40, 50, 60)]);
+
+        let colors = raw_to_rgb24(&buf, PixelLayout::Bgra8, 2, 1, 8, 1).unwrap();
+        assert_eq!(colors, vec![Rgb24::new(30, 20, 10)]);
+
+        let colors = raw_to_rgb24(&buf, PixelLayout::GrayAlpha8, 4, 1, 8, 50).unwrap();
+        assert_eq!(colors, vec![Rgb24::new(30, 30, 30), Rgb24::new(40, 40, 40)]);
+    }
+
+    #[test]
+    fn raw_stride() {
+        // Two rows of a single RGB pixel, padded to four bytes.
+        let buf = [1, 2, 3, 0, 4, 5, 6];
+
+        let colors = raw_to_rgb24(&buf, PixelLayout::Rgb8, 1, 2, 4, 0).unwrap();
+        assert_eq!(colors, vec![Rgb24::new(1, 2, 3), Rgb24::new(4, 5, 6)]);
+
+        assert!(matches!(
+            raw_to_rgb24(&buf, PixelLayout::Rgb8, 2, 2, 4, 0),
+            Err(Error::InvalidBuffer(_))
+        ));
+        assert!(matches!(
+            raw_to_rgb24(&buf, PixelLayout::Rgb8, 1, 3, 4, 0),
+            Err(Error::InvalidBuffer(_))
+        ));
+    }
+
+    #[test]
+    fn raw_overflow() {
+        let buf = [0; 16];
+        let max = usize::MAX;
+
+        for (width, height, stride) in [(max, 1, max), (1, max, max), (2, 3, max)] {
+            assert!(matches!(
+                raw_to_rgb24(&buf, PixelLayout::Rgba8, width, height, stride, 0),
+                Err(Error::InvalidBuffer(_))
+            ));
+        }
+
+        let mask = [255; 4];
+        assert!(matches!(
+            raw_masked_to_rgb24(&buf, PixelLayout::Rgb8, 2, max, 6, 0, Some(&mask)),
+            Err(Error::InvalidBuffer(_))
+        ));
+
+        // Empty rows are not iterated.
+        let colors = raw_to_rgb24(&buf, PixelLayout::Rgb8, 0, max, 0, 0).unwrap();
+        assert!(colors.is_empty());
+    }
+
+    #[test]
+    fn raw_mask() {
+        let buf = [1, 2, 3, 0, 4, 5, 6, 0, 7, 8, 9];
+        let mask = [255, 0, 200];
+
+        let colors = raw_masked_to_rgb24(&buf, PixelLayout::Rgb8, 1, 3, 4, 0, Some(&mask)).unwrap();
+        assert_eq!(colors, vec![Rgb24::new(1, 2, 3), Rgb24::new(7, 8, 9)]);
+
+        assert!(matches!(
+            raw_masked_to_rgb24(&buf, PixelLayout::Rgb8, 1, 3, 4, 0, Some(&mask[..2])),
+            Err(Error::InvalidBuffer(_))
+        ));
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn image_mask() {
+        let img = image::DynamicImage::ImageRgb8(image::RgbImage::from_fn(2, 2, |x, y| {
+            image::Rgb([x as u8, y as u8, 0])
+        }));
+        let mask = image::GrayImage::from_fn(2, 2, |x, y| image::Luma([(x == y) as u8 * 255]));
+
+        let colors = masked_image_to_rgb24(&img, 0, Some(&mask)).unwrap();
+        assert_eq!(colors, vec![Rgb24::new(0, 0, 0), Rgb24::new(1, 1, 0)]);
+        assert_eq!(masked_image_to_rgb24(&img, 0, None).unwrap().len(), 4);
+
+        let small = image::GrayImage::new(1, 2);
+        assert!(masked_image_to_rgb24(&img, 0, Some(&small)).is_err());
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn image_weights() {
+        let img = image::DynamicImage::ImageRgba8(image::RgbaImage::from_fn(2, 2, |x, y| {
+            image::Rgba([x as u8, y as u8, 0, if x + y == 2 { 0 } else { 255 }])
+        }));
+        let mask = image::GrayImage::from_fn(2, 2, |x, _| image::Luma([(x == 0) as u8 * 255]));
+
+        let pixels = weighted_image_to_rgb24(&img, 1, None, &[1, 0, 3, 4]).unwrap();
+        assert_eq!(
+            pixels,
+            vec![(Rgb24::new(0, 0, 0), 1), (Rgb24::new(0, 1, 0), 3)]
+        );
+
+        let pixels = weighted_image_to_rgb24(&img, 0, Some(&mask), &[1, 2, 3, 4]).unwrap();
+        assert_eq!(
+            pixels,
+            vec![(Rgb24::new(0, 0, 0), 1), (Rgb24::new(0, 1, 0), 3)]
+        );
+
+        assert!(weighted_image_to_rgb24(&img, 0, None, &[1, 2, 3]).is_err());
+    }
+
+    #[cfg(any(feature = "png", feature = "all-formats"))]
+    #[test]
+    fn image_bytes() {
+        let img = image::RgbaImage::from_fn(2, 2, |x, y| {
+            image::Rgba([
+                x as u8 * 100,
+                y as u8 * 100,
+                7,
+                if x == 1 { 255 } else { 0 },
+            ])
+        });
+
+        let mut bytes = std::io::Cursor::new(Vec::new());
+        img.write_to(&mut bytes, image::ImageFormat::Png).unwrap();
+
+        let colors = bytes_to_rgb24(bytes.get_ref(), 128).unwrap();
+        assert_eq!(colors, vec![Rgb24::new(100, 0, 7), Rgb24::new(100, 100, 7)]);
+        assert_eq!(colors, rgba_image_to_rgb24(&img, 128));
+
+        assert!(bytes_to_rgb24(&[1, 2, 3], 0).is_err());
+    }
+}
@@ -1,43 +1,99 @@
+pub mod builder;
 pub mod color;
+pub mod contrast;
+pub mod cvd;
+pub mod error;
+pub mod filter;
+pub mod format;
+pub mod input;
+pub mod lock;
 pub mod median_cut;
+pub mod metrics;
+pub mod names;
 pub mod octree;
+pub mod quantizer;
+pub mod remap;
+pub mod roles;
+pub mod sort;
+pub mod swatch;
+pub mod theme;
+pub mod weight;
 
-use std::path::Path;
-
+pub use builder::PaletteBuilder;
 use color::Rgb24;
-use median_cut::median_cut;
-use octree::octree;
-
-type ResColors = Result<Vec<color::Rgb24>, image::ImageError>;
-
-/// Reads an image file to an RGB24 buffer.
-pub fn img_to_rgb24<P: AsRef<Path>>(path: P, alpha_min: u8) -> ResColors {
-    let img = image::open(path)?;
-    let img = img.to_rgba8();
-
-    let colors = img
-        .chunks_exact(4)
-        .filter(|c| c[3] >= alpha_min)
-        .map(|ch| color::Rgb24::new(ch[0], ch[1], ch[2]))
-        .collect();
+pub use error::{Error, Result};
+pub use filter::Exclusion;
+pub use format::PaletteFormat;
+#[cfg(feature = "image")]
+pub use input::{
+    bytes_to_rgb24, dynamic_image_to_rgb24, img_to_rgb24, masked_image_to_rgb24,
+    rgb_image_to_rgb24, rgba_image_to_rgb24, weighted_image_to_rgb24,
+};
+pub use input::{raw_masked_to_rgb24, raw_to_rgb24, PixelLayout};
+pub use lock::LockedColors;
+pub use names::{ColorNames, NameSet};
+pub use quantizer::{MedianCutOptions, OctreeOptions, Quantizer};
+pub use roles::{Role, RoleSwatch};
+pub use sort::SortOrder;
+pub use swatch::{SwatchLayout, SwatchOptions};
+pub use theme::{Theme, ThemeTarget};
+pub use weight::Weighting;
 
-    Ok(colors)
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+/// Built-in quantization method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
 pub enum Method {
     MedianCut,
     Octree,
 }
 
-/// Quantize a palette with the specified method.
-pub fn solve(method: Method, colors: Vec<Rgb24>, palette_size: usize) -> Vec<Rgb24> {
+impl Quantizer for Method {
+    fn quantize(&self, colors: Vec<Rgb24>, palette_size: usize) -> Result<Vec<Rgb24>> {
+        match self {
+            Method::MedianCut => MedianCutOptions::default().quantize(colors, palette_size),
+            Method::Octree => OctreeOptions::default().quantize(colors, palette_size),
+        }
+    }
+
+    fn exact_size(&self) -> bool {
+        match self {
+            Method::MedianCut => MedianCutOptions::default().exact_size(),
+            Method::Octree => OctreeOptions::default().exact_size(),
+        }
+    }
+}
+
+/// Quantize a palette with the specified quantizer.
+pub fn solve<Q: Quantizer + ?Sized>(
+    quantizer: &Q,
+    colors: Vec<Rgb24>,
+    palette_size: usize,
+) -> Result<Vec<Rgb24>> {
+    validate(colors.len(), palette_size)?;
+
     if palette_size >= colors.len() {
-        return colors.to_vec();
+        return Ok(colors);
     }
 
-    match method {
-        Method::MedianCut => median_cut(colors, palette_size),
-        Method::Octree => octree(&colors, palette_size),
+    quantizer.quantize(colors, palette_size)
+}
+
+/// Quantize a palette from an iterator of colors.
+pub fn solve_iter<Q, I>(quantizer: &Q, colors: I, palette_size: usize) -> Result<Vec<Rgb24>>
+where
+    Q: Quantizer + ?Sized,
+    I: IntoIterator<Item = Rgb24>,
+{
+    solve(quantizer, colors.into_iter().collect(), palette_size)
+}
+
+/// Checks that a palette of `palette_size` can be made from `len` colors.
+pub(crate) fn validate(len: usize, palette_size: usize) -> Result<()> {
+    if palette_size == 0 {
+        Err(Error::InvalidSize(palette_size))
+    } else if len == 0 {
+        Err(Error::EmptyInput)
+    } else {
+        Ok(())
     }
 }
@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+
+use crate::color::{Lab, Rgb24};
+use crate::error::{Error, Result};
+use crate::quantizer::Quantizer;
+
+/// Colors that must appear in a palette.
+///
+/// Pixels within `tolerance` (CIE76 Delta E) of a locked color are assigned
+/// to it and left out of quantization, so the remaining palette slots are
+/// spent on the rest of the image instead of near-duplicates.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct LockedColors {
+    colors: Vec<Rgb24>,
+    labs: Vec<Lab>,
+    tolerance: f32,
+}
+
+impl LockedColors {
+    /// Default distance within which pixels are assigned to a locked color.
+    pub const DEFAULT_TOLERANCE: f32 = 10.0;
+
+    /// Creates a set of locked colors. Duplicates are removed.
+    pub fn new(colors: &[Rgb24]) -> Self {
+        let mut unique = Vec::new();
+        for &color in colors {
+            if !unique.contains(&color) {
+                unique.push(color);
+            }
+        }
+
+        Self {
+            labs: unique.iter().map(Rgb24::to_lab).collect(),
+            colors: unique,
+            tolerance: Self::DEFAULT_TOLERANCE,
+        }
+    }
+
+    /// Sets the distance within which pixels are assigned to a locked color.
+    pub fn with_tolerance(mut self, tolerance: f32) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Retrieves the locked colors.
+    pub fn colors(&self) -> &[Rgb24] {
+        &self.colors
+    }
+
+    /// Retrieves the number of locked colors.
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    /// Checks whether no colors are locked.
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    /// Checks whether a color is assigned to one of the locked colors.
+    pub fn claims(&self, color: &Rgb24) -> bool {
+        let lab = color.to_lab();
+        self.labs
+            .iter()
+            .any(|locked| lab.delta_e(locked) <= self.tolerance)
+    }
+
+    /// Removes the colors assigned to locked colors.
+    pub fn filter(&self, colors: &[Rgb24]) -> Vec<Rgb24> {
+        self.filter_by(colors, |color| *color)
+    }
+
+    /// Removes the weighted pixels assigned to locked colors.
+    pub fn filter_pixels(&self, pixels: &[(Rgb24, u64)]) -> Vec<(Rgb24, u64)> {
+        self.filter_by(pixels, |(color, _)| *color)
+    }
+
+    /// Removes the items whose color is assigned to locked colors.
+    fn filter_by<T: Copy>(&self, items: &[T], color: impl Fn(&T) -> Rgb24) -> Vec<T> {
+        let mut cache = HashMap::new();
+
+        items
+            .iter()
+            .filter(|item| {
+                let color = color(item);
+                !*cache.entry(color).or_insert_with(|| self.claims(&color))
+            })
+            .copied()
+            .collect()
+    }
+
+    /// Quantizes a palette that starts with the locked colors.
+    ///
+    /// The remaining `palette_size - self.len()` colors are quantized from
+    /// the pixels not assigned to a locked color. Fails if there are more
+    /// locked colors than `palette_size`.
+    ///
+    pub fn solve<Q: Quantizer + ?Sized>(
+        &self,
+        quantizer: &Q,
+        colors: Vec<Rgb24>,
+        palette_size: usize,
+    ) -> Result<Vec<Rgb24>> {
+        crate::validate(colors.len(), palette_size)?;
+
+        let rest = self.filter(&colors);
+        self.complete(palette_size, rest.len(), |size| {
+            crate::solve(quantizer, rest, size)
+        })
+    }
+
+    /// Prepends the locked colors to a palette quantized from the `rest`
+    /// pixels not assigned to them.
+    pub(crate) fn complete<F>(
+        &self,
+        palette_size: usize,
+        rest: usize,
+        quantize: F,
+    ) -> Result<Vec<Rgb24>>
+    where
+        F: FnOnce(usize) -> Result<Vec<Rgb24>>,
+    {
+        if self.len() > palette_size {
+            return Err(Error::InvalidSize(palette_size));
+        }
+
+        let mut palette = self.colors.clone();
+
+        let free = palette_size - self.len();
+        if free > 0 && rest > 0 {
+            let quantized = quantize(free)?;
+            palette.extend(quantized.into_iter().filter(|c| !self.colors.contains(c)));
+        }
+
+        Ok(palette)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Method;
+
+    fn colors() -> Vec<Rgb24> {
+        let mut colors = vec![Rgb24::new(0, 0, 0); 50];
+        colors.extend(vec![Rgb24::new(4, 3, 2); 50]);
+        colors.extend(vec![Rgb24::new(250, 250, 250); 10]);
+        colors.extend(vec![Rgb24::new(200, 30, 30); 20]);
+        colors.extend(vec![Rgb24::new(30, 30, 200); 20]);
+        colors
+    }
+
+    #[test]
+    fn lock_solve() {
+        let locked = LockedColors::new(&[Rgb24::new(255, 255, 255), Rgb24::new(0, 0, 0)]);
+
+        // Near-black and near-white pixels are claimed by the locked colors,
+        // leaving the remaining slots to red and blue.
+        let palette = locked.solve(&Method::MedianCut, colors(), 4).unwrap();
+        assert_eq!(
+            palette,
+            vec![
+                Rgb24::new(255, 255, 255),
+                Rgb24::new(0, 0, 0),
+                Rgb24::new(200, 30, 30),
+                Rgb24::new(30, 30, 200),
+            ]
+        );
+
+        let palette = locked.solve(&Method::MedianCut, colors(), 2).unwrap();
+        assert_eq!(palette, locked.colors());
+
+        assert!(locked.solve(&Method::MedianCut, colors(), 1).is_err());
+        assert!(locked.solve(&Method::MedianCut, vec![], 4).is_err());
+    }
+
+    #[test]
+    fn lock_solid() {
+        let black = Rgb24::new(0, 0, 0);
+        let red = Rgb24::new(200, 30, 30);
+        let locked = LockedColors::new(&[black]);
+
+        // Fewer distinct colors than free slots yield a shorter palette.
+        for method in [Method::MedianCut, Method::Octree] {
+            let palette = locked.solve(&method, vec![red; 64], 4).unwrap();
+            assert_eq!(palette, vec![black, red]);
+        }
+
+        let mut colors = vec![red; 32];
+        colors.extend(vec![black; 32]);
+        let palette = locked.solve(&Method::MedianCut, colors, 4).unwrap();
+        assert_eq!(palette, vec![black, red]);
+    }
+
+    #[test]
+    fn lock_tolerance() {
+        let locked = LockedColors::new(&[Rgb24::new(0, 0, 0), Rgb24::new(0, 0, 0)]);
+        assert_eq!(locked.len(), 1);
+        assert!(locked.claims(&Rgb24::new(4, 3, 2)));
+        assert!(!locked.claims(&Rgb24::new(60, 60, 60)));
+
+        let locked = locked.with_tolerance(0.0);
+        assert!(!locked.claims(&Rgb24::new(4, 3, 2)));
+        assert_eq!(locked.filter(&colors()).len(), 100);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::color::{RGBChannel, Rgb24};
+use crate::error::Result;
 
 /// Bucket represented as an offset in a sequential container.
 /// Also saves the maximum channel delta and a tag for that channel.
@@ -38,7 +39,18 @@ impl Bucket {
 ///
 /// The resulting palette is the averages within each bucket.
 ///
-pub fn median_cut(colors: Vec<Rgb24>, palette_size: usize) -> Vec<Rgb24> {
+/// Fails if `colors` is empty or `palette_size` is zero. If there are no
+/// more colors than `palette_size`, the colors are returned unchanged.
+/// If there are fewer distinct colors than `palette_size`, the palette is
+/// smaller than requested.
+///
+pub fn median_cut(colors: Vec<Rgb24>, palette_size: usize) -> Result<Vec<Rgb24>> {
+    crate::validate(colors.len(), palette_size)?;
+
+    if palette_size >= colors.len() {
+        return Ok(colors);
+    }
+
     let mut colors = colors;
     let mut buckets: Vec<Bucket> = Vec::with_capacity(palette_size + 1);
 
@@ -49,11 +61,16 @@ pub fn median_cut(colors: Vec<Rgb24>, palette_size: usize) -> Vec<Rgb24> {
     buckets.push(Bucket::new(colors.len(), chan, 0));
 
     while buckets.len() <= palette_size {
-        let (i, max_bucket) = buckets
+        // Buckets of a single distinct color, like the sentinel, have no
+        // delta and cannot be split.
+        let Some((i, max_bucket)) = buckets
             .iter()
             .enumerate()
+            .filter(|(_, bucket)| bucket.delta > 0)
             .max_by(|(_, x), (_, y)| x.delta.cmp(&y.delta))
-            .unwrap();
+        else {
+            break;
+        };
 
         let start = buckets[i].offset;
         let end = buckets[i + 1].offset;
@@ -70,16 +87,97 @@ pub fn median_cut(colors: Vec<Rgb24>, palette_size: usize) -> Vec<Rgb24> {
         buckets.insert(i + 1, Bucket::new(mid, chan1, delta1));
     }
 
-    buckets
+    let palette = buckets
         .iter()
         .zip(buckets.iter().skip(1))
         .map(|(a, b)| Rgb24::average(&colors[a.offset..b.offset]))
-        .collect()
+        .collect();
+
+    Ok(palette)
+}
+
+/// Finds the median cut of a weighted color histogram.
+///
+/// Behaves like [`median_cut`], except that each color carries a count.
+/// Buckets are split at the weighted median rather than the midpoint,
+/// and the palette colors are the weighted averages within each bucket.
+///
+/// Buckets holding a single distinct color cannot be split, so the
+/// resulting palette may be smaller than `palette_size`.
+///
+/// Fails if `histogram` is empty or `palette_size` is zero.
+///
+pub fn median_cut_histogram(
+    histogram: Vec<(Rgb24, u64)>,
+    palette_size: usize,
+) -> Result<Vec<Rgb24>> {
+    crate::validate(histogram.len(), palette_size)?;
+
+    let mut histogram = histogram;
+    let mut buckets: Vec<Bucket> = Vec::with_capacity(palette_size + 1);
+
+    let (chan, delta) = Rgb24::max_channel_delta(histogram.iter().map(|(c, _)| c));
+    buckets.push(Bucket::new(0, chan, delta));
+
+    // Sentinel bucket used for splitting at the end of the container.
+    buckets.push(Bucket::new(histogram.len(), chan, 0));
+
+    while buckets.len() <= palette_size {
+        let Some((i, max_bucket)) = buckets
+            .iter()
+            .zip(buckets.iter().skip(1))
+            .enumerate()
+            .filter(|(_, (a, b))| b.offset - a.offset > 1)
+            .map(|(i, (a, _))| (i, a))
+            .max_by(|(_, x), (_, y)| x.delta.cmp(&y.delta))
+        else {
+            break;
+        };
+
+        let start = buckets[i].offset;
+        let end = buckets[i + 1].offset;
+
+        let channel = max_bucket.channel.to_usize();
+        let bucket_colors = &mut histogram[start..end];
+        bucket_colors.sort_by_key(|(c, _)| c[channel]);
+
+        // Split where the cumulative weight reaches half of the bucket weight,
+        // keeping at least one color on each side.
+        // Weights are user-supplied, so sums saturate instead of wrapping.
+        let total = bucket_colors
+            .iter()
+            .fold(0u64, |sum, (_, w)| sum.saturating_add(*w));
+        let mut acc = 0u64;
+        let split = bucket_colors
+            .iter()
+            .position(|(_, w)| {
+                acc = acc.saturating_add(*w);
+                acc >= total - acc
+            })
+            .map_or(1, |p| p + 1);
+        let mid = start + split.clamp(1, end - start - 1);
+
+        let (chan0, delta0) =
+            Rgb24::max_channel_delta(histogram[start..mid].iter().map(|(c, _)| c));
+        let (chan1, delta1) = Rgb24::max_channel_delta(histogram[mid..end].iter().map(|(c, _)| c));
+
+        buckets[i] = Bucket::new(start, chan0, delta0);
+        buckets.insert(i + 1, Bucket::new(mid, chan1, delta1));
+    }
+
+    let palette = buckets
+        .iter()
+        .zip(buckets.iter().skip(1))
+        .map(|(a, b)| Rgb24::weighted_average(&histogram[a.offset..b.offset]))
+        .collect();
+
+    Ok(palette)
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::error::Error;
 
     #[test]
     fn median_cut() {
@@ -116,7 +214,7 @@ mod test {
             Rgb24::new(201, 98, 236),
             Rgb24::new(202, 196, 185),
         ];
-        assert_eq!(palette, super::median_cut(colors.to_vec(), 8));
+        assert_eq!(palette, super::median_cut(colors.to_vec(), 8).unwrap());
 
         let palette = vec![
             Rgb24::new(47, 56, 6),
@@ -136,6 +234,77 @@ mod test {
             Rgb24::new(247, 200, 162),
             Rgb24::new(191, 236, 235),
         ];
-        assert_eq!(palette, super::median_cut(colors.to_vec(), 16));
+        assert_eq!(palette, super::median_cut(colors.to_vec(), 16).unwrap());
+    }
+
+    #[test]
+    fn median_cut_histogram() {
+        let histogram = vec![
+            (Rgb24::new(10, 10, 10), 4),
+            (Rgb24::new(20, 10, 10), 4),
+            (Rgb24::new(200, 10, 10), 1),
+            (Rgb24::new(220, 10, 10), 1),
+            (Rgb24::new(10, 10, 240), 2),
+        ];
+
+        let palette = super::median_cut_histogram(histogram.clone(), 1).unwrap();
+        assert_eq!(palette, vec![Rgb24::new(47, 10, 48)]);
+
+        let palette = super::median_cut_histogram(histogram.clone(), 2).unwrap();
+        assert_eq!(
+            palette,
+            vec![Rgb24::new(15, 10, 10), Rgb24::new(110, 10, 125)]
+        );
+
+        // Cannot split past the number of distinct colors.
+        let palette = super::median_cut_histogram(histogram, 16).unwrap();
+        assert_eq!(palette.len(), 5);
+    }
+
+    #[test]
+    fn median_cut_histogram_large_weights() {
+        let weight = u64::MAX / 2;
+        let histogram = vec![
+            (Rgb24::new(0, 0, 0), weight),
+            (Rgb24::new(100, 100, 100), weight),
+            (Rgb24::new(200, 200, 200), weight),
+        ];
+
+        let palette = super::median_cut_histogram(histogram.clone(), 1).unwrap();
+        assert_eq!(palette, vec![Rgb24::new(100, 100, 100)]);
+
+        let palette = super::median_cut_histogram(histogram, 2).unwrap();
+        assert_eq!(palette.len(), 2);
+    }
+
+    #[test]
+    fn median_cut_few_colors() {
+        let gray = Rgb24::new(5, 5, 5);
+        let red = Rgb24::new(200, 10, 10);
+
+        assert_eq!(super::median_cut(vec![gray; 10], 3).unwrap(), vec![gray]);
+
+        let mut colors = vec![gray; 32];
+        colors.extend(vec![red; 32]);
+        assert_eq!(super::median_cut(colors, 4).unwrap(), vec![gray, red]);
+    }
+
+    #[test]
+    fn median_cut_invalid_input() {
+        let colors = vec![Rgb24::new(1, 2, 3), Rgb24::new(4, 5, 6)];
+
+        assert!(matches!(
+            super::median_cut(colors.clone(), 0),
+            Err(Error::InvalidSize(0))
+        ));
+        assert!(matches!(
+            super::median_cut(vec![], 4),
+            Err(Error::EmptyInput)
+        ));
+        assert!(matches!(
+            super::median_cut_histogram(vec![], 4),
+            Err(Error::EmptyInput)
+        ));
+        assert_eq!(super::median_cut(colors.clone(), 2).unwrap(), colors);
     }
 }
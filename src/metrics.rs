@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+
+use crate::color::Rgb24;
+
+/// Finds the per-channel mean squared error between two color buffers.
+///
+/// Colors are compared pairwise, so both buffers should have the same
+/// length. Returns zero if either buffer is empty.
+///
+pub fn mse(a: &[Rgb24], b: &[Rgb24]) -> f64 {
+    let len = a.len().min(b.len());
+    if len == 0 {
+        return 0.0;
+    }
+
+    let sum: u64 = a
+        .iter()
+        .zip(b)
+        .map(|(x, y)| x.distance_squared(y) as u64)
+        .sum();
+
+    sum as f64 / (3 * len) as f64
+}
+
+/// Finds the peak signal-to-noise ratio in decibels between two color buffers.
+///
+/// Identical buffers have an infinite PSNR.
+///
+pub fn psnr(a: &[Rgb24], b: &[Rgb24]) -> f64 {
+    let mse = mse(a, b);
+    10.0 * f64::log10(255.0 * 255.0 / mse)
+}
+
+/// Finds the mean perceptual distance (Delta E) between two color buffers.
+///
+/// Colors are compared pairwise. Returns zero if either buffer is empty.
+///
+pub fn mean_delta_e(a: &[Rgb24], b: &[Rgb24]) -> f64 {
+    let len = a.len().min(b.len());
+    if len == 0 {
+        return 0.0;
+    }
+
+    let mut cache = HashMap::new();
+    let sum: f64 = a
+        .iter()
+        .zip(b)
+        .map(|(x, y)| *cache.entry((*x, *y)).or_insert_with(|| x.delta_e(y)) as f64)
+        .sum();
+
+    sum / len as f64
+}
+
+/// Finds the mean perceptual distance from each color in `from` to the
+/// closest color in `to`.
+///
+/// Returns zero if either palette is empty.
+///
+pub fn palette_distance(from: &[Rgb24], to: &[Rgb24]) -> f64 {
+    if from.is_empty() || to.is_empty() {
+        return 0.0;
+    }
+
+    let to: Vec<_> = to.iter().map(Rgb24::to_lab).collect();
+
+    let sum: f64 = from
+        .iter()
+        .map(|color| {
+            let lab = color.to_lab();
+            to.iter()
+                .map(|other| lab.delta_e(other))
+                .fold(f32::INFINITY, f32::min) as f64
+        })
+        .sum();
+
+    sum / from.len() as f64
+}
+
+/// Summary statistics of a color buffer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorStats {
+    /// Number of colors.
+    pub count: usize,
+    /// Number of distinct colors.
+    pub unique: usize,
+    /// Channel-wise mean.
+    pub mean: Rgb24,
+    /// Channel-wise minimum.
+    pub min: Rgb24,
+    /// Channel-wise maximum.
+    pub max: Rgb24,
+    /// Distinct colors with their counts, most frequent first.
+    pub histogram: Vec<(Rgb24, u64)>,
+}
+
+impl ColorStats {
+    /// Computes statistics for a color buffer. Returns `None` if it is empty.
+    pub fn from_colors(colors: &[Rgb24]) -> Option<Self> {
+        let first = colors.first()?;
+
+        let mut counts = HashMap::new();
+        for &color in colors {
+            *counts.entry(color).or_insert(0) += 1;
+        }
+
+        let mut histogram: Vec<_> = counts.into_iter().collect();
+        histogram.sort_unstable_by_key(|&(c, n)| (u64::MAX - n, c.r(), c.g(), c.b()));
+
+        let (min, max) = colors.iter().fold((*first, *first), |(min, max), val| {
+            (Rgb24::min(&min, val), Rgb24::max(&max, val))
+        });
+
+        Some(Self {
+            count: colors.len(),
+            unique: histogram.len(),
+            mean: Rgb24::average(colors),
+            min,
+            max,
+            histogram,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn metrics_error() {
+        let a = vec![Rgb24::new(0, 0, 0), Rgb24::new(10, 10, 10)];
+        let b = vec![Rgb24::new(0, 0, 0), Rgb24::new(13, 14, 10)];
+
+        assert_eq!(mse(&a, &b), 25.0 / 6.0);
+        assert!((psnr(&a, &b) - 41.93).abs() < 0.01);
+        assert!(psnr(&a, &a).is_infinite());
+
+        assert_eq!(mean_delta_e(&a, &a), 0.0);
+        assert!(mean_delta_e(&a, &b) > 0.0);
+    }
+
+    #[test]
+    fn metrics_palette_distance() {
+        let a = vec![Rgb24::new(0, 0, 0), Rgb24::new(255, 255, 255)];
+        let b = vec![Rgb24::new(0, 0, 0)];
+
+        assert_eq!(palette_distance(&b, &a), 0.0);
+        assert!((palette_distance(&a, &b) - 50.0).abs() < 0.01);
+        assert_eq!(palette_distance(&a, &[]), 0.0);
+    }
+
+    #[test]
+    fn metrics_color_stats() {
+        let colors = vec![
+            Rgb24::new(10, 200, 30),
+            Rgb24::new(50, 0, 90),
+            Rgb24::new(50, 0, 90),
+        ];
+
+        let stats = ColorStats::from_colors(&colors).unwrap();
+        assert_eq!(stats.count, 3);
+        assert_eq!(stats.unique, 2);
+        assert_eq!(stats.mean, Rgb24::new(37, 67, 70));
+        assert_eq!(stats.min, Rgb24::new(10, 0, 30));
+        assert_eq!(stats.max, Rgb24::new(50, 200, 90));
+        assert_eq!(stats.histogram[0], (Rgb24::new(50, 0, 90), 2));
+
+        assert_eq!(ColorStats::from_colors(&[]), None);
+    }
+}
@@ -0,0 +1,1003 @@
+use crate::color::{Lab, Rgb24};
+use crate::error::{Error, Result};
+
+/// Built-in set of color names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum NameSet {
+    /// CSS Color Module Level 4 named colors.
+    #[default]
+    Css,
+    /// X11 `rgb.txt` names, including numbered variants such as `red1` to
+    /// `red4` and `gray0` to `gray100`. Some names, such as `gray` and
+    /// `green`, have other values than in CSS.
+    X11,
+}
+
+/// CSS named colors. Synonyms such as `gray` and `grey` share a value, and
+/// the first one is reported.
+const CSS: [(&str, Rgb24); 148] = [
+    ("aliceblue", Rgb24::new(240, 248, 255)),
+    ("antiquewhite", Rgb24::new(250, 235, 215)),
+    ("aqua", Rgb24::new(0, 255, 255)),
+    ("aquamarine", Rgb24::new(127, 255, 212)),
+    ("azure", Rgb24::new(240, 255, 255)),
+    ("beige", Rgb24::new(245, 245, 220)),
+    ("bisque", Rgb24::new(255, 228, 196)),
+    ("black", Rgb24::new(0, 0, 0)),
+    ("blanchedalmond", Rgb24::new(255, 235, 205)),
+    ("blue", Rgb24::new(0, 0, 255)),
+    ("blueviolet", Rgb24::new(138, 43, 226)),
+    ("brown", Rgb24::new(165, 42, 42)),
+    ("burlywood", Rgb24::new(222, 184, 135)),
+    ("cadetblue", Rgb24::new(95, 158, 160)),
+    ("chartreuse", Rgb24::new(127, 255, 0)),
+    ("chocolate", Rgb24::new(210, 105, 30)),
+    ("coral", Rgb24::new(255, 127, 80)),
+    ("cornflowerblue", Rgb24::new(100, 149, 237)),
+    ("cornsilk", Rgb24::new(255, 248, 220)),
+    ("crimson", Rgb24::new(220, 20, 60)),
+    ("cyan", Rgb24::new(0, 255, 255)),
+    ("darkblue", Rgb24::new(0, 0, 139)),
+    ("darkcyan", Rgb24::new(0, 139, 139)),
+    ("darkgoldenrod", Rgb24::new(184, 134, 11)),
+    ("darkgray", Rgb24::new(169, 169, 169)),
+    ("darkgreen", Rgb24::new(0, 100, 0)),
+    ("darkgrey", Rgb24::new(169, 169, 169)),
+    ("darkkhaki", Rgb24::new(189, 183, 107)),
+    ("darkmagenta", Rgb24::new(139, 0, 139)),
+    ("darkolivegreen", Rgb24::new(85, 107, 47)),
+    ("darkorange", Rgb24::new(255, 140, 0)),
+    ("darkorchid", Rgb24::new(153, 50, 204)),
+    ("darkred", Rgb24::new(139, 0, 0)),
+    ("darksalmon", Rgb24::new(233, 150, 122)),
+    ("darkseagreen", Rgb24::new(143, 188, 143)),
+    ("darkslateblue", Rgb24::new(72, 61, 139)),
+    ("darkslategray", Rgb24::new(47, 79, 79)),
+    ("darkslategrey", Rgb24::new(47, 79, 79)),
+    ("darkturquoise", Rgb24::new(0, 206, 209)),
+    ("darkviolet", Rgb24::new(148, 0, 211)),
+    ("deeppink", Rgb24::new(255, 20, 147)),
+    ("deepskyblue", Rgb24::new(0, 191, 255)),
+    ("dimgray", Rgb24::new(105, 105, 105)),
+    ("dimgrey", Rgb24::new(105, 105, 105)),
+    ("dodgerblue", Rgb24::new(30, 144, 255)),
+    ("firebrick", Rgb24::new(178, 34, 34)),
+    ("floralwhite", Rgb24::new(255, 250, 240)),
+    ("forestgreen", Rgb24::new(34, 139, 34)),
+    ("fuchsia", Rgb24::new(255, 0, 255)),
+    ("gainsboro", Rgb24::new(220, 220, 220)),
+    ("ghostwhite", Rgb24::new(248, 248, 255)),
+    ("gold", Rgb24::new(255, 215, 0)),
+    ("goldenrod", Rgb24::new(218, 165, 32)),
+    ("gray", Rgb24::new(128, 128, 128)),
+    ("green", Rgb24::new(0, 128, 0)),
+    ("greenyellow", Rgb24::new(173, 255, 47)),
+    ("grey", Rgb24::new(128, 128, 128)),
+    ("honeydew", Rgb24::new(240, 255, 240)),
+    ("hotpink", Rgb24::new(255, 105, 180)),
+    ("indianred", Rgb24::new(205, 92, 92)),
+    ("indigo", Rgb24::new(75, 0, 130)),
+    ("ivory", Rgb24::new(255, 255, 240)),
+    ("khaki", Rgb24::new(240, 230, 140)),
+    ("lavender", Rgb24::new(230, 230, 250)),
+    ("lavenderblush", Rgb24::new(255, 240, 245)),
+    ("lawngreen", Rgb24::new(124, 252, 0)),
+    ("lemonchiffon", Rgb24::new(255, 250, 205)),
+    ("lightblue", Rgb24::new(173, 216, 230)),
+    ("lightcoral", Rgb24::new(240, 128, 128)),
+    ("lightcyan", Rgb24::new(224, 255, 255)),
+    ("lightgoldenrodyellow", Rgb24::new(250, 250, 210)),
+    ("lightgray", Rgb24::new(211, 211, 211)),
+    ("lightgreen", Rgb24::new(144, 238, 144)),
+    ("lightgrey", Rgb24::new(211, 211, 211)),
+    ("lightpink", Rgb24::new(255, 182, 193)),
+    ("lightsalmon", Rgb24::new(255, 160, 122)),
+    ("lightseagreen", Rgb24::new(32, 178, 170)),
+    ("lightskyblue", Rgb24::new(135, 206, 250)),
+    ("lightslategray", Rgb24::new(119, 136, 153)),
+    ("lightslategrey", Rgb24::new(119, 136, 153)),
+    ("lightsteelblue", Rgb24::new(176, 196, 222)),
+    ("lightyellow", Rgb24::new(255, 255, 224)),
+    ("lime", Rgb24::new(0, 255, 0)),
+    ("limegreen", Rgb24::new(50, 205, 50)),
+    ("linen", Rgb24::new(250, 240, 230)),
+    ("magenta", Rgb24::new(255, 0, 255)),
+    ("maroon", Rgb24::new(128, 0, 0)),
+    ("mediumaquamarine", Rgb24::new(102, 205, 170)),
+    ("mediumblue", Rgb24::new(0, 0, 205)),
+    ("mediumorchid", Rgb24::new(186, 85, 211)),
+    ("mediumpurple", Rgb24::new(147, 112, 219)),
+    ("mediumseagreen", Rgb24::new(60, 179, 113)),
+    ("mediumslateblue", Rgb24::new(123, 104, 238)),
+    ("mediumspringgreen", Rgb24::new(0, 250, 154)),
+    ("mediumturquoise", Rgb24::new(72, 209, 204)),
+    ("mediumvioletred", Rgb24::new(199, 21, 133)),
+    ("midnightblue", Rgb24::new(25, 25, 112)),
+    ("mintcream", Rgb24::new(245, 255, 250)),
+    ("mistyrose", Rgb24::new(255, 228, 225)),
+    ("moccasin", Rgb24::new(255, 228, 181)),
+    ("navajowhite", Rgb24::new(255, 222, 173)),
+    ("navy", Rgb24::new(0, 0, 128)),
+    ("oldlace", Rgb24::new(253, 245, 230)),
+    ("olive", Rgb24::new(128, 128, 0)),
+    ("olivedrab", Rgb24::new(107, 142, 35)),
+    ("orange", Rgb24::new(255, 165, 0)),
+    ("orangered", Rgb24::new(255, 69, 0)),
+    ("orchid", Rgb24::new(218, 112, 214)),
+    ("palegoldenrod", Rgb24::new(238, 232, 170)),
+    ("palegreen", Rgb24::new(152, 251, 152)),
+    ("paleturquoise", Rgb24::new(175, 238, 238)),
+    ("palevioletred", Rgb24::new(219, 112, 147)),
+    ("papayawhip", Rgb24::new(255, 239, 213)),
+    ("peachpuff", Rgb24::new(255, 218, 185)),
+    ("peru", Rgb24::new(205, 133, 63)),
+    ("pink", Rgb24::new(255, 192, 203)),
+    ("plum", Rgb24::new(221, 160, 221)),
+    ("powderblue", Rgb24::new(176, 224, 230)),
+    ("purple", Rgb24::new(128, 0, 128)),
+    ("rebeccapurple", Rgb24::new(102, 51, 153)),
+    ("red", Rgb24::new(255, 0, 0)),
+    ("rosybrown", Rgb24::new(188, 143, 143)),
+    ("royalblue", Rgb24::new(65, 105, 225)),
+    ("saddlebrown", Rgb24::new(139, 69, 19)),
+    ("salmon", Rgb24::new(250, 128, 114)),
+    ("sandybrown", Rgb24::new(244, 164, 96)),
+    ("seagreen", Rgb24::new(46, 139, 87)),
+    ("seashell", Rgb24::new(255, 245, 238)),
+    ("sienna", Rgb24::new(160, 82, 45)),
+    ("silver", Rgb24::new(192, 192, 192)),
+    ("skyblue", Rgb24::new(135, 206, 235)),
+    ("slateblue", Rgb24::new(106, 90, 205)),
+    ("slategray", Rgb24::new(112, 128, 144)),
+    ("slategrey", Rgb24::new(112, 128, 144)),
+    ("snow", Rgb24::new(255, 250, 250)),
+    ("springgreen", Rgb24::new(0, 255, 127)),
+    ("steelblue", Rgb24::new(70, 130, 180)),
+    ("tan", Rgb24::new(210, 180, 140)),
+    ("teal", Rgb24::new(0, 128, 128)),
+    ("thistle", Rgb24::new(216, 191, 216)),
+    ("tomato", Rgb24::new(255, 99, 71)),
+    ("turquoise", Rgb24::new(64, 224, 208)),
+    ("violet", Rgb24::new(238, 130, 238)),
+    ("wheat", Rgb24::new(245, 222, 179)),
+    ("white", Rgb24::new(255, 255, 255)),
+    ("whitesmoke", Rgb24::new(245, 245, 245)),
+    ("yellow", Rgb24::new(255, 255, 0)),
+    ("yellowgreen", Rgb24::new(154, 205, 50)),
+];
+
+/// X11 `rgb.txt` colors, in file order so that base names come before their
+/// numbered variants. Spaced names are joined and lowercased, and the names
+/// added by X.Org in 2014, such as `webgray` and `rebeccapurple`, are
+/// appended.
+const X11: [(&str, Rgb24); 677] = [
+    ("snow", Rgb24::new(255, 250, 250)),
+    ("ghostwhite", Rgb24::new(248, 248, 255)),
+    ("whitesmoke", Rgb24::new(245, 245, 245)),
+    ("gainsboro", Rgb24::new(220, 220, 220)),
+    ("floralwhite", Rgb24::new(255, 250, 240)),
+    ("oldlace", Rgb24::new(253, 245, 230)),
+    ("linen", Rgb24::new(250, 240, 230)),
+    ("antiquewhite", Rgb24::new(250, 235, 215)),
+    ("papayawhip", Rgb24::new(255, 239, 213)),
+    ("blanchedalmond", Rgb24::new(255, 235, 205)),
+    ("bisque", Rgb24::new(255, 228, 196)),
+    ("peachpuff", Rgb24::new(255, 218, 185)),
+    ("navajowhite", Rgb24::new(255, 222, 173)),
+    ("moccasin", Rgb24::new(255, 228, 181)),
+    ("cornsilk", Rgb24::new(255, 248, 220)),
+    ("ivory", Rgb24::new(255, 255, 240)),
+    ("lemonchiffon", Rgb24::new(255, 250, 205)),
+    ("seashell", Rgb24::new(255, 245, 238)),
+    ("honeydew", Rgb24::new(240, 255, 240)),
+    ("mintcream", Rgb24::new(245, 255, 250)),
+    ("azure", Rgb24::new(240, 255, 255)),
+    ("aliceblue", Rgb24::new(240, 248, 255)),
+    ("lavender", Rgb24::new(230, 230, 250)),
+    ("lavenderblush", Rgb24::new(255, 240, 245)),
+    ("mistyrose", Rgb24::new(255, 228, 225)),
+    ("white", Rgb24::new(255, 255, 255)),
+    ("black", Rgb24::new(0, 0, 0)),
+    ("darkslategray", Rgb24::new(47, 79, 79)),
+    ("darkslategrey", Rgb24::new(47, 79, 79)),
+    ("dimgray", Rgb24::new(105, 105, 105)),
+    ("dimgrey", Rgb24::new(105, 105, 105)),
+    ("slategray", Rgb24::new(112, 128, 144)),
+    ("slategrey", Rgb24::new(112, 128, 144)),
+    ("lightslategray", Rgb24::new(119, 136, 153)),
+    ("lightslategrey", Rgb24::new(119, 136, 153)),
+    ("gray", Rgb24::new(190, 190, 190)),
+    ("grey", Rgb24::new(190, 190, 190)),
+    ("lightgrey", Rgb24::new(211, 211, 211)),
+    ("lightgray", Rgb24::new(211, 211, 211)),
+    ("midnightblue", Rgb24::new(25, 25, 112)),
+    ("navy", Rgb24::new(0, 0, 128)),
+    ("navyblue", Rgb24::new(0, 0, 128)),
+    ("cornflowerblue", Rgb24::new(100, 149, 237)),
+    ("darkslateblue", Rgb24::new(72, 61, 139)),
+    ("slateblue", Rgb24::new(106, 90, 205)),
+    ("mediumslateblue", Rgb24::new(123, 104, 238)),
+    ("lightslateblue", Rgb24::new(132, 112, 255)),
+    ("mediumblue", Rgb24::new(0, 0, 205)),
+    ("royalblue", Rgb24::new(65, 105, 225)),
+    ("blue", Rgb24::new(0, 0, 255)),
+    ("dodgerblue", Rgb24::new(30, 144, 255)),
+    ("deepskyblue", Rgb24::new(0, 191, 255)),
+    ("skyblue", Rgb24::new(135, 206, 235)),
+    ("lightskyblue", Rgb24::new(135, 206, 250)),
+    ("steelblue", Rgb24::new(70, 130, 180)),
+    ("lightsteelblue", Rgb24::new(176, 196, 222)),
+    ("lightblue", Rgb24::new(173, 216, 230)),
+    ("powderblue", Rgb24::new(176, 224, 230)),
+    ("paleturquoise", Rgb24::new(175, 238, 238)),
+    ("darkturquoise", Rgb24::new(0, 206, 209)),
+    ("mediumturquoise", Rgb24::new(72, 209, 204)),
+    ("turquoise", Rgb24::new(64, 224, 208)),
+    ("cyan", Rgb24::new(0, 255, 255)),
+    ("lightcyan", Rgb24::new(224, 255, 255)),
+    ("cadetblue", Rgb24::new(95, 158, 160)),
+    ("mediumaquamarine", Rgb24::new(102, 205, 170)),
+    ("aquamarine", Rgb24::new(127, 255, 212)),
+    ("darkgreen", Rgb24::new(0, 100, 0)),
+    ("darkolivegreen", Rgb24::new(85, 107, 47)),
+    ("darkseagreen", Rgb24::new(143, 188, 143)),
+    ("seagreen", Rgb24::new(46, 139, 87)),
+    ("mediumseagreen", Rgb24::new(60, 179, 113)),
+    ("lightseagreen", Rgb24::new(32, 178, 170)),
+    ("palegreen", Rgb24::new(152, 251, 152)),
+    ("springgreen", Rgb24::new(0, 255, 127)),
+    ("lawngreen", Rgb24::new(124, 252, 0)),
+    ("green", Rgb24::new(0, 255, 0)),
+    ("chartreuse", Rgb24::new(127, 255, 0)),
+    ("mediumspringgreen", Rgb24::new(0, 250, 154)),
+    ("greenyellow", Rgb24::new(173, 255, 47)),
+    ("limegreen", Rgb24::new(50, 205, 50)),
+    ("yellowgreen", Rgb24::new(154, 205, 50)),
+    ("forestgreen", Rgb24::new(34, 139, 34)),
+    ("olivedrab", Rgb24::new(107, 142, 35)),
+    ("darkkhaki", Rgb24::new(189, 183, 107)),
+    ("khaki", Rgb24::new(240, 230, 140)),
+    ("palegoldenrod", Rgb24::new(238, 232, 170)),
+    ("lightgoldenrodyellow", Rgb24::new(250, 250, 210)),
+    ("lightyellow", Rgb24::new(255, 255, 224)),
+    ("yellow", Rgb24::new(255, 255, 0)),
+    ("gold", Rgb24::new(255, 215, 0)),
+    ("lightgoldenrod", Rgb24::new(238, 221, 130)),
+    ("goldenrod", Rgb24::new(218, 165, 32)),
+    ("darkgoldenrod", Rgb24::new(184, 134, 11)),
+    ("rosybrown", Rgb24::new(188, 143, 143)),
+    ("indianred", Rgb24::new(205, 92, 92)),
+    ("saddlebrown", Rgb24::new(139, 69, 19)),
+    ("sienna", Rgb24::new(160, 82, 45)),
+    ("peru", Rgb24::new(205, 133, 63)),
+    ("burlywood", Rgb24::new(222, 184, 135)),
+    ("beige", Rgb24::new(245, 245, 220)),
+    ("wheat", Rgb24::new(245, 222, 179)),
+    ("sandybrown", Rgb24::new(244, 164, 96)),
+    ("tan", Rgb24::new(210, 180, 140)),
+    ("chocolate", Rgb24::new(210, 105, 30)),
+    ("firebrick", Rgb24::new(178, 34, 34)),
+    ("brown", Rgb24::new(165, 42, 42)),
+    ("darksalmon", Rgb24::new(233, 150, 122)),
+    ("salmon", Rgb24::new(250, 128, 114)),
+    ("lightsalmon", Rgb24::new(255, 160, 122)),
+    ("orange", Rgb24::new(255, 165, 0)),
+    ("darkorange", Rgb24::new(255, 140, 0)),
+    ("coral", Rgb24::new(255, 127, 80)),
+    ("lightcoral", Rgb24::new(240, 128, 128)),
+    ("tomato", Rgb24::new(255, 99, 71)),
+    ("orangered", Rgb24::new(255, 69, 0)),
+    ("red", Rgb24::new(255, 0, 0)),
+    ("hotpink", Rgb24::new(255, 105, 180)),
+    ("deeppink", Rgb24::new(255, 20, 147)),
+    ("pink", Rgb24::new(255, 192, 203)),
+    ("lightpink", Rgb24::new(255, 182, 193)),
+    ("palevioletred", Rgb24::new(219, 112, 147)),
+    ("maroon", Rgb24::new(176, 48, 96)),
+    ("mediumvioletred", Rgb24::new(199, 21, 133)),
+    ("violetred", Rgb24::new(208, 32, 144)),
+    ("magenta", Rgb24::new(255, 0, 255)),
+    ("violet", Rgb24::new(238, 130, 238)),
+    ("plum", Rgb24::new(221, 160, 221)),
+    ("orchid", Rgb24::new(218, 112, 214)),
+    ("mediumorchid", Rgb24::new(186, 85, 211)),
+    ("darkorchid", Rgb24::new(153, 50, 204)),
+    ("darkviolet", Rgb24::new(148, 0, 211)),
+    ("blueviolet", Rgb24::new(138, 43, 226)),
+    ("purple", Rgb24::new(160, 32, 240)),
+    ("mediumpurple", Rgb24::new(147, 112, 219)),
+    ("thistle", Rgb24::new(216, 191, 216)),
+    ("snow1", Rgb24::new(255, 250, 250)),
+    ("snow2", Rgb24::new(238, 233, 233)),
+    ("snow3", Rgb24::new(205, 201, 201)),
+    ("snow4", Rgb24::new(139, 137, 137)),
+    ("seashell1", Rgb24::new(255, 245, 238)),
+    ("seashell2", Rgb24::new(238, 229, 222)),
+    ("seashell3", Rgb24::new(205, 197, 191)),
+    ("seashell4", Rgb24::new(139, 134, 130)),
+    ("antiquewhite1", Rgb24::new(255, 239, 219)),
+    ("antiquewhite2", Rgb24::new(238, 223, 204)),
+    ("antiquewhite3", Rgb24::new(205, 192, 176)),
+    ("antiquewhite4", Rgb24::new(139, 131, 120)),
+    ("bisque1", Rgb24::new(255, 228, 196)),
+    ("bisque2", Rgb24::new(238, 213, 183)),
+    ("bisque3", Rgb24::new(205, 183, 158)),
+    ("bisque4", Rgb24::new(139, 125, 107)),
+    ("peachpuff1", Rgb24::new(255, 218, 185)),
+    ("peachpuff2", Rgb24::new(238, 203, 173)),
+    ("peachpuff3", Rgb24::new(205, 175, 149)),
+    ("peachpuff4", Rgb24::new(139, 119, 101)),
+    ("navajowhite1", Rgb24::new(255, 222, 173)),
+    ("navajowhite2", Rgb24::new(238, 207, 161)),
+    ("navajowhite3", Rgb24::new(205, 179, 139)),
+    ("navajowhite4", Rgb24::new(139, 121, 94)),
+    ("lemonchiffon1", Rgb24::new(255, 250, 205)),
+    ("lemonchiffon2", Rgb24::new(238, 233, 191)),
+    ("lemonchiffon3", Rgb24::new(205, 201, 165)),
+    ("lemonchiffon4", Rgb24::new(139, 137, 112)),
+    ("cornsilk1", Rgb24::new(255, 248, 220)),
+    ("cornsilk2", Rgb24::new(238, 232, 205)),
+    ("cornsilk3", Rgb24::new(205, 200, 177)),
+    ("cornsilk4", Rgb24::new(139, 136, 120)),
+    ("ivory1", Rgb24::new(255, 255, 240)),
+    ("ivory2", Rgb24::new(238, 238, 224)),
+    ("ivory3", Rgb24::new(205, 205, 193)),
+    ("ivory4", Rgb24::new(139, 139, 131)),
+    ("honeydew1", Rgb24::new(240, 255, 240)),
+    ("honeydew2", Rgb24::new(224, 238, 224)),
+    ("honeydew3", Rgb24::new(193, 205, 193)),
+    ("honeydew4", Rgb24::new(131, 139, 131)),
+    ("lavenderblush1", Rgb24::new(255, 240, 245)),
+    ("lavenderblush2", Rgb24::new(238, 224, 229)),
+    ("lavenderblush3", Rgb24::new(205, 193, 197)),
+    ("lavenderblush4", Rgb24::new(139, 131, 134)),
+    ("mistyrose1", Rgb24::new(255, 228, 225)),
+    ("mistyrose2", Rgb24::new(238, 213, 210)),
+    ("mistyrose3", Rgb24::new(205, 183, 181)),
+    ("mistyrose4", Rgb24::new(139, 125, 123)),
+    ("azure1", Rgb24::new(240, 255, 255)),
+    ("azure2", Rgb24::new(224, 238, 238)),
+    ("azure3", Rgb24::new(193, 205, 205)),
+    ("azure4", Rgb24::new(131, 139, 139)),
+    ("slateblue1", Rgb24::new(131, 111, 255)),
+    ("slateblue2", Rgb24::new(122, 103, 238)),
+    ("slateblue3", Rgb24::new(105, 89, 205)),
+    ("slateblue4", Rgb24::new(71, 60, 139)),
+    ("royalblue1", Rgb24::new(72, 118, 255)),
+    ("royalblue2", Rgb24::new(67, 110, 238)),
+    ("royalblue3", Rgb24::new(58, 95, 205)),
+    ("royalblue4", Rgb24::new(39, 64, 139)),
+    ("blue1", Rgb24::new(0, 0, 255)),
+    ("blue2", Rgb24::new(0, 0, 238)),
+    ("blue3", Rgb24::new(0, 0, 205)),
+    ("blue4", Rgb24::new(0, 0, 139)),
+    ("dodgerblue1", Rgb24::new(30, 144, 255)),
+    ("dodgerblue2", Rgb24::new(28, 134, 238)),
+    ("dodgerblue3", Rgb24::new(24, 116, 205)),
+    ("dodgerblue4", Rgb24::new(16, 78, 139)),
+    ("steelblue1", Rgb24::new(99, 184, 255)),
+    ("steelblue2", Rgb24::new(92, 172, 238)),
+    ("steelblue3", Rgb24::new(79, 148, 205)),
+    ("steelblue4", Rgb24::new(54, 100, 139)),
+    ("deepskyblue1", Rgb24::new(0, 191, 255)),
+    ("deepskyblue2", Rgb24::new(0, 178, 238)),
+    ("deepskyblue3", Rgb24::new(0, 154, 205)),
+    ("deepskyblue4", Rgb24::new(0, 104, 139)),
+    ("skyblue1", Rgb24::new(135, 206, 255)),
+    ("skyblue2", Rgb24::new(126, 192, 238)),
+    ("skyblue3", Rgb24::new(108, 166, 205)),
+    ("skyblue4", Rgb24::new(74, 112, 139)),
+    ("lightskyblue1", Rgb24::new(176, 226, 255)),
+    ("lightskyblue2", Rgb24::new(164, 211, 238)),
+    ("lightskyblue3", Rgb24::new(141, 182, 205)),
+    ("lightskyblue4", Rgb24::new(96, 123, 139)),
+    ("slategray1", Rgb24::new(198, 226, 255)),
+    ("slategray2", Rgb24::new(185, 211, 238)),
+    ("slategray3", Rgb24::new(159, 182, 205)),
+    ("slategray4", Rgb24::new(108, 123, 139)),
+    ("lightsteelblue1", Rgb24::new(202, 225, 255)),
+    ("lightsteelblue2", Rgb24::new(188, 210, 238)),
+    ("lightsteelblue3", Rgb24::new(162, 181, 205)),
+    ("lightsteelblue4", Rgb24::new(110, 123, 139)),
+    ("lightblue1", Rgb24::new(191, 239, 255)),
+    ("lightblue2", Rgb24::new(178, 223, 238)),
+    ("lightblue3", Rgb24::new(154, 192, 205)),
+    ("lightblue4", Rgb24::new(104, 131, 139)),
+    ("lightcyan1", Rgb24::new(224, 255, 255)),
+    ("lightcyan2", Rgb24::new(209, 238, 238)),
+    ("lightcyan3", Rgb24::new(180, 205, 205)),
+    ("lightcyan4", Rgb24::new(122, 139, 139)),
+    ("paleturquoise1", Rgb24::new(187, 255, 255)),
+    ("paleturquoise2", Rgb24::new(174, 238, 238)),
+    ("paleturquoise3", Rgb24::new(150, 205, 205)),
+    ("paleturquoise4", Rgb24::new(102, 139, 139)),
+    ("cadetblue1", Rgb24::new(152, 245, 255)),
+    ("cadetblue2", Rgb24::new(142, 229, 238)),
+    ("cadetblue3", Rgb24::new(122, 197, 205)),
+    ("cadetblue4", Rgb24::new(83, 134, 139)),
+    ("turquoise1", Rgb24::new(0, 245, 255)),
+    ("turquoise2", Rgb24::new(0, 229, 238)),
+    ("turquoise3", Rgb24::new(0, 197, 205)),
+    ("turquoise4", Rgb24::new(0, 134, 139)),
+    ("cyan1", Rgb24::new(0, 255, 255)),
+    ("cyan2", Rgb24::new(0, 238, 238)),
+    ("cyan3", Rgb24::new(0, 205, 205)),
+    ("cyan4", Rgb24::new(0, 139, 139)),
+    ("darkslategray1", Rgb24::new(151, 255, 255)),
+    ("darkslategray2", Rgb24::new(141, 238, 238)),
+    ("darkslategray3", Rgb24::new(121, 205, 205)),
+    ("darkslategray4", Rgb24::new(82, 139, 139)),
+    ("aquamarine1", Rgb24::new(127, 255, 212)),
+    ("aquamarine2", Rgb24::new(118, 238, 198)),
+    ("aquamarine3", Rgb24::new(102, 205, 170)),
+    ("aquamarine4", Rgb24::new(69, 139, 116)),
+    ("darkseagreen1", Rgb24::new(193, 255, 193)),
+    ("darkseagreen2", Rgb24::new(180, 238, 180)),
+    ("darkseagreen3", Rgb24::new(155, 205, 155)),
+    ("darkseagreen4", Rgb24::new(105, 139, 105)),
+    ("seagreen1", Rgb24::new(84, 255, 159)),
+    ("seagreen2", Rgb24::new(78, 238, 148)),
+    ("seagreen3", Rgb24::new(67, 205, 128)),
+    ("seagreen4", Rgb24::new(46, 139, 87)),
+    ("palegreen1", Rgb24::new(154, 255, 154)),
+    ("palegreen2", Rgb24::new(144, 238, 144)),
+    ("palegreen3", Rgb24::new(124, 205, 124)),
+    ("palegreen4", Rgb24::new(84, 139, 84)),
+    ("springgreen1", Rgb24::new(0, 255, 127)),
+    ("springgreen2", Rgb24::new(0, 238, 118)),
+    ("springgreen3", Rgb24::new(0, 205, 102)),
+    ("springgreen4", Rgb24::new(0, 139, 69)),
+    ("green1", Rgb24::new(0, 255, 0)),
+    ("green2", Rgb24::new(0, 238, 0)),
+    ("green3", Rgb24::new(0, 205, 0)),
+    ("green4", Rgb24::new(0, 139, 0)),
+    ("chartreuse1", Rgb24::new(127, 255, 0)),
+    ("chartreuse2", Rgb24::new(118, 238, 0)),
+    ("chartreuse3", Rgb24::new(102, 205, 0)),
+    ("chartreuse4", Rgb24::new(69, 139, 0)),
+    ("olivedrab1", Rgb24::new(192, 255, 62)),
+    ("olivedrab2", Rgb24::new(179, 238, 58)),
+    ("olivedrab3", Rgb24::new(154, 205, 50)),
+    ("olivedrab4", Rgb24::new(105, 139, 34)),
+    ("darkolivegreen1", Rgb24::new(202, 255, 112)),
+    ("darkolivegreen2", Rgb24::new(188, 238, 104)),
+    ("darkolivegreen3", Rgb24::new(162, 205, 90)),
+    ("darkolivegreen4", Rgb24::new(110, 139, 61)),
+    ("khaki1", Rgb24::new(255, 246, 143)),
+    ("khaki2", Rgb24::new(238, 230, 133)),
+    ("khaki3", Rgb24::new(205, 198, 115)),
+    ("khaki4", Rgb24::new(139, 134, 78)),
+    ("lightgoldenrod1", Rgb24::new(255, 236, 139)),
+    ("lightgoldenrod2", Rgb24::new(238, 220, 130)),
+    ("lightgoldenrod3", Rgb24::new(205, 190, 112)),
+    ("lightgoldenrod4", Rgb24::new(139, 129, 76)),
+    ("lightyellow1", Rgb24::new(255, 255, 224)),
+    ("lightyellow2", Rgb24::new(238, 238, 209)),
+    ("lightyellow3", Rgb24::new(205, 205, 180)),
+    ("lightyellow4", Rgb24::new(139, 139, 122)),
+    ("yellow1", Rgb24::new(255, 255, 0)),
+    ("yellow2", Rgb24::new(238, 238, 0)),
+    ("yellow3", Rgb24::new(205, 205, 0)),
+    ("yellow4", Rgb24::new(139, 139, 0)),
+    ("gold1", Rgb24::new(255, 215, 0)),
+    ("gold2", Rgb24::new(238, 201, 0)),
+    ("gold3", Rgb24::new(205, 173, 0)),
+    ("gold4", Rgb24::new(139, 117, 0)),
+    ("goldenrod1", Rgb24::new(255, 193, 37)),
+    ("goldenrod2", Rgb24::new(238, 180, 34)),
+    ("goldenrod3", Rgb24::new(205, 155, 29)),
+    ("goldenrod4", Rgb24::new(139, 105, 20)),
+    ("darkgoldenrod1", Rgb24::new(255, 185, 15)),
+    ("darkgoldenrod2", Rgb24::new(238, 173, 14)),
+    ("darkgoldenrod3", Rgb24::new(205, 149, 12)),
+    ("darkgoldenrod4", Rgb24::new(139, 101, 8)),
+    ("rosybrown1", Rgb24::new(255, 193, 193)),
+    ("rosybrown2", Rgb24::new(238, 180, 180)),
+    ("rosybrown3", Rgb24::new(205, 155, 155)),
+    ("rosybrown4", Rgb24::new(139, 105, 105)),
+    ("indianred1", Rgb24::new(255, 106, 106)),
+    ("indianred2", Rgb24::new(238, 99, 99)),
+    ("indianred3", Rgb24::new(205, 85, 85)),
+    ("indianred4", Rgb24::new(139, 58, 58)),
+    ("sienna1", Rgb24::new(255, 130, 71)),
+    ("sienna2", Rgb24::new(238, 121, 66)),
+    ("sienna3", Rgb24::new(205, 104, 57)),
+    ("sienna4", Rgb24::new(139, 71, 38)),
+    ("burlywood1", Rgb24::new(255, 211, 155)),
+    ("burlywood2", Rgb24::new(238, 197, 145)),
+    ("burlywood3", Rgb24::new(205, 170, 125)),
+    ("burlywood4", Rgb24::new(139, 115, 85)),
+    ("wheat1", Rgb24::new(255, 231, 186)),
+    ("wheat2", Rgb24::new(238, 216, 174)),
+    ("wheat3", Rgb24::new(205, 186, 150)),
+    ("wheat4", Rgb24::new(139, 126, 102)),
+    ("tan1", Rgb24::new(255, 165, 79)),
+    ("tan2", Rgb24::new(238, 154, 73)),
+    ("tan3", Rgb24::new(205, 133, 63)),
+    ("tan4", Rgb24::new(139, 90, 43)),
+    ("chocolate1", Rgb24::new(255, 127, 36)),
+    ("chocolate2", Rgb24::new(238, 118, 33)),
+    ("chocolate3", Rgb24::new(205, 102, 29)),
+    ("chocolate4", Rgb24::new(139, 69, 19)),
+    ("firebrick1", Rgb24::new(255, 48, 48)),
+    ("firebrick2", Rgb24::new(238, 44, 44)),
+    ("firebrick3", Rgb24::new(205, 38, 38)),
+    ("firebrick4", Rgb24::new(139, 26, 26)),
+    ("brown1", Rgb24::new(255, 64, 64)),
+    ("brown2", Rgb24::new(238, 59, 59)),
+    ("brown3", Rgb24::new(205, 51, 51)),
+    ("brown4", Rgb24::new(139, 35, 35)),
+    ("salmon1", Rgb24::new(255, 140, 105)),
+    ("salmon2", Rgb24::new(238, 130, 98)),
+    ("salmon3", Rgb24::new(205, 112, 84)),
+    ("salmon4", Rgb24::new(139, 76, 57)),
+    ("lightsalmon1", Rgb24::new(255, 160, 122)),
+    ("lightsalmon2", Rgb24::new(238, 149, 114)),
+    ("lightsalmon3", Rgb24::new(205, 129, 98)),
+    ("lightsalmon4", Rgb24::new(139, 87, 66)),
+    ("orange1", Rgb24::new(255, 165, 0)),
+    ("orange2", Rgb24::new(238, 154, 0)),
+    ("orange3", Rgb24::new(205, 133, 0)),
+    ("orange4", Rgb24::new(139, 90, 0)),
+    ("darkorange1", Rgb24::new(255, 127, 0)),
+    ("darkorange2", Rgb24::new(238, 118, 0)),
+    ("darkorange3", Rgb24::new(205, 102, 0)),
+    ("darkorange4", Rgb24::new(139, 69, 0)),
+    ("coral1", Rgb24::new(255, 114, 86)),
+    ("coral2", Rgb24::new(238, 106, 80)),
+    ("coral3", Rgb24::new(205, 91, 69)),
+    ("coral4", Rgb24::new(139, 62, 47)),
+    ("tomato1", Rgb24::new(255, 99, 71)),
+    ("tomato2", Rgb24::new(238, 92, 66)),
+    ("tomato3", Rgb24::new(205, 79, 57)),
+    ("tomato4", Rgb24::new(139, 54, 38)),
+    ("orangered1", Rgb24::new(255, 69, 0)),
+    ("orangered2", Rgb24::new(238, 64, 0)),
+    ("orangered3", Rgb24::new(205, 55, 0)),
+    ("orangered4", Rgb24::new(139, 37, 0)),
+    ("red1", Rgb24::new(255, 0, 0)),
+    ("red2", Rgb24::new(238, 0, 0)),
+    ("red3", Rgb24::new(205, 0, 0)),
+    ("red4", Rgb24::new(139, 0, 0)),
+    ("debianred", Rgb24::new(215, 7, 81)),
+    ("deeppink1", Rgb24::new(255, 20, 147)),
+    ("deeppink2", Rgb24::new(238, 18, 137)),
+    ("deeppink3", Rgb24::new(205, 16, 118)),
+    ("deeppink4", Rgb24::new(139, 10, 80)),
+    ("hotpink1", Rgb24::new(255, 110, 180)),
+    ("hotpink2", Rgb24::new(238, 106, 167)),
+    ("hotpink3", Rgb24::new(205, 96, 144)),
+    ("hotpink4", Rgb24::new(139, 58, 98)),
+    ("pink1", Rgb24::new(255, 181, 197)),
+    ("pink2", Rgb24::new(238, 169, 184)),
+    ("pink3", Rgb24::new(205, 145, 158)),
+    ("pink4", Rgb24::new(139, 99, 108)),
+    ("lightpink1", Rgb24::new(255, 174, 185)),
+    ("lightpink2", Rgb24::new(238, 162, 173)),
+    ("lightpink3", Rgb24::new(205, 140, 149)),
+    ("lightpink4", Rgb24::new(139, 95, 101)),
+    ("palevioletred1", Rgb24::new(255, 130, 171)),
+    ("palevioletred2", Rgb24::new(238, 121, 159)),
+    ("palevioletred3", Rgb24::new(205, 104, 137)),
+    ("palevioletred4", Rgb24::new(139, 71, 93)),
+    ("maroon1", Rgb24::new(255, 52, 179)),
+    ("maroon2", Rgb24::new(238, 48, 167)),
+    ("maroon3", Rgb24::new(205, 41, 144)),
+    ("maroon4", Rgb24::new(139, 28, 98)),
+    ("violetred1", Rgb24::new(255, 62, 150)),
+    ("violetred2", Rgb24::new(238, 58, 140)),
+    ("violetred3", Rgb24::new(205, 50, 120)),
+    ("violetred4", Rgb24::new(139, 34, 82)),
+    ("magenta1", Rgb24::new(255, 0, 255)),
+    ("magenta2", Rgb24::new(238, 0, 238)),
+    ("magenta3", Rgb24::new(205, 0, 205)),
+    ("magenta4", Rgb24::new(139, 0, 139)),
+    ("orchid1", Rgb24::new(255, 131, 250)),
+    ("orchid2", Rgb24::new(238, 122, 233)),
+    ("orchid3", Rgb24::new(205, 105, 201)),
+    ("orchid4", Rgb24::new(139, 71, 137)),
+    ("plum1", Rgb24::new(255, 187, 255)),
+    ("plum2", Rgb24::new(238, 174, 238)),
+    ("plum3", Rgb24::new(205, 150, 205)),
+    ("plum4", Rgb24::new(139, 102, 139)),
+    ("mediumorchid1", Rgb24::new(224, 102, 255)),
+    ("mediumorchid2", Rgb24::new(209, 95, 238)),
+    ("mediumorchid3", Rgb24::new(180, 82, 205)),
+    ("mediumorchid4", Rgb24::new(122, 55, 139)),
+    ("darkorchid1", Rgb24::new(191, 62, 255)),
+    ("darkorchid2", Rgb24::new(178, 58, 238)),
+    ("darkorchid3", Rgb24::new(154, 50, 205)),
+    ("darkorchid4", Rgb24::new(104, 34, 139)),
+    ("purple1", Rgb24::new(155, 48, 255)),
+    ("purple2", Rgb24::new(145, 44, 238)),
+    ("purple3", Rgb24::new(125, 38, 205)),
+    ("purple4", Rgb24::new(85, 26, 139)),
+    ("mediumpurple1", Rgb24::new(171, 130, 255)),
+    ("mediumpurple2", Rgb24::new(159, 121, 238)),
+    ("mediumpurple3", Rgb24::new(137, 104, 205)),
+    ("mediumpurple4", Rgb24::new(93, 71, 139)),
+    ("thistle1", Rgb24::new(255, 225, 255)),
+    ("thistle2", Rgb24::new(238, 210, 238)),
+    ("thistle3", Rgb24::new(205, 181, 205)),
+    ("thistle4", Rgb24::new(139, 123, 139)),
+    ("gray0", Rgb24::new(0, 0, 0)),
+    ("grey0", Rgb24::new(0, 0, 0)),
+    ("gray1", Rgb24::new(3, 3, 3)),
+    ("grey1", Rgb24::new(3, 3, 3)),
+    ("gray2", Rgb24::new(5, 5, 5)),
+    ("grey2", Rgb24::new(5, 5, 5)),
+    ("gray3", Rgb24::new(8, 8, 8)),
+    ("grey3", Rgb24::new(8, 8, 8)),
+    ("gray4", Rgb24::new(10, 10, 10)),
+    ("grey4", Rgb24::new(10, 10, 10)),
+    ("gray5", Rgb24::new(13, 13, 13)),
+    ("grey5", Rgb24::new(13, 13, 13)),
+    ("gray6", Rgb24::new(15, 15, 15)),
+    ("grey6", Rgb24::new(15, 15, 15)),
+    ("gray7", Rgb24::new(18, 18, 18)),
+    ("grey7", Rgb24::new(18, 18, 18)),
+    ("gray8", Rgb24::new(20, 20, 20)),
+    ("grey8", Rgb24::new(20, 20, 20)),
+    ("gray9", Rgb24::new(23, 23, 23)),
+    ("grey9", Rgb24::new(23, 23, 23)),
+    ("gray10", Rgb24::new(26, 26, 26)),
+    ("grey10", Rgb24::new(26, 26, 26)),
+    ("gray11", Rgb24::new(28, 28, 28)),
+    ("grey11", Rgb24::new(28, 28, 28)),
+    ("gray12", Rgb24::new(31, 31, 31)),
+    ("grey12", Rgb24::new(31, 31, 31)),
+    ("gray13", Rgb24::new(33, 33, 33)),
+    ("grey13", Rgb24::new(33, 33, 33)),
+    ("gray14", Rgb24::new(36, 36, 36)),
+    ("grey14", Rgb24::new(36, 36, 36)),
+    ("gray15", Rgb24::new(38, 38, 38)),
+    ("grey15", Rgb24::new(38, 38, 38)),
+    ("gray16", Rgb24::new(41, 41, 41)),
+    ("grey16", Rgb24::new(41, 41, 41)),
+    ("gray17", Rgb24::new(43, 43, 43)),
+    ("grey17", Rgb24::new(43, 43, 43)),
+    ("gray18", Rgb24::new(46, 46, 46)),
+    ("grey18", Rgb24::new(46, 46, 46)),
+    ("gray19", Rgb24::new(48, 48, 48)),
+    ("grey19", Rgb24::new(48, 48, 48)),
+    ("gray20", Rgb24::new(51, 51, 51)),
+    ("grey20", Rgb24::new(51, 51, 51)),
+    ("gray21", Rgb24::new(54, 54, 54)),
+    ("grey21", Rgb24::new(54, 54, 54)),
+    ("gray22", Rgb24::new(56, 56, 56)),
+    ("grey22", Rgb24::new(56, 56, 56)),
+    ("gray23", Rgb24::new(59, 59, 59)),
+    ("grey23", Rgb24::new(59, 59, 59)),
+    ("gray24", Rgb24::new(61, 61, 61)),
+    ("grey24", Rgb24::new(61, 61, 61)),
+    ("gray25", Rgb24::new(64, 64, 64)),
+    ("grey25", Rgb24::new(64, 64, 64)),
+    ("gray26", Rgb24::new(66, 66, 66)),
+    ("grey26", Rgb24::new(66, 66, 66)),
+    ("gray27", Rgb24::new(69, 69, 69)),
+    ("grey27", Rgb24::new(69, 69, 69)),
+    ("gray28", Rgb24::new(71, 71, 71)),
+    ("grey28", Rgb24::new(71, 71, 71)),
+    ("gray29", Rgb24::new(74, 74, 74)),
+    ("grey29", Rgb24::new(74, 74, 74)),
+    ("gray30", Rgb24::new(77, 77, 77)),
+    ("grey30", Rgb24::new(77, 77, 77)),
+    ("gray31", Rgb24::new(79, 79, 79)),
+    ("grey31", Rgb24::new(79, 79, 79)),
+    ("gray32", Rgb24::new(82, 82, 82)),
+    ("grey32", Rgb24::new(82, 82, 82)),
+    ("gray33", Rgb24::new(84, 84, 84)),
+    ("grey33", Rgb24::new(84, 84, 84)),
+    ("gray34", Rgb24::new(87, 87, 87)),
+    ("grey34", Rgb24::new(87, 87, 87)),
+    ("gray35", Rgb24::new(89, 89, 89)),
+    ("grey35", Rgb24::new(89, 89, 89)),
+    ("gray36", Rgb24::new(92, 92, 92)),
+    ("grey36", Rgb24::new(92, 92, 92)),
+    ("gray37", Rgb24::new(94, 94, 94)),
+    ("grey37", Rgb24::new(94, 94, 94)),
+    ("gray38", Rgb24::new(97, 97, 97)),
+    ("grey38", Rgb24::new(97, 97, 97)),
+    ("gray39", Rgb24::new(99, 99, 99)),
+    ("grey39", Rgb24::new(99, 99, 99)),
+    ("gray40", Rgb24::new(102, 102, 102)),
+    ("grey40", Rgb24::new(102, 102, 102)),
+    ("gray41", Rgb24::new(105, 105, 105)),
+    ("grey41", Rgb24::new(105, 105, 105)),
+    ("gray42", Rgb24::new(107, 107, 107)),
+    ("grey42", Rgb24::new(107, 107, 107)),
+    ("gray43", Rgb24::new(110, 110, 110)),
+    ("grey43", Rgb24::new(110, 110, 110)),
+    ("gray44", Rgb24::new(112, 112, 112)),
+    ("grey44", Rgb24::new(112, 112, 112)),
+    ("gray45", Rgb24::new(115, 115, 115)),
+    ("grey45", Rgb24::new(115, 115, 115)),
+    ("gray46", Rgb24::new(117, 117, 117)),
+    ("grey46", Rgb24::new(117, 117, 117)),
+    ("gray47", Rgb24::new(120, 120, 120)),
+    ("grey47", Rgb24::new(120, 120, 120)),
+    ("gray48", Rgb24::new(122, 122, 122)),
+    ("grey48", Rgb24::new(122, 122, 122)),
+    ("gray49", Rgb24::new(125, 125, 125)),
+    ("grey49", Rgb24::new(125, 125, 125)),
+    ("gray50", Rgb24::new(127, 127, 127)),
+    ("grey50", Rgb24::new(127, 127, 127)),
+    ("gray51", Rgb24::new(130, 130, 130)),
+    ("grey51", Rgb24::new(130, 130, 130)),
+    ("gray52", Rgb24::new(133, 133, 133)),
+    ("grey52", Rgb24::new(133, 133, 133)),
+    ("gray53", Rgb24::new(135, 135, 135)),
+    ("grey53", Rgb24::new(135, 135, 135)),
+    ("gray54", Rgb24::new(138, 138, 138)),
+    ("grey54", Rgb24::new(138, 138, 138)),
+    ("gray55", Rgb24::new(140, 140, 140)),
+    ("grey55", Rgb24::new(140, 140, 140)),
+    ("gray56", Rgb24::new(143, 143, 143)),
+    ("grey56", Rgb24::new(143, 143, 143)),
+    ("gray57", Rgb24::new(145, 145, 145)),
+    ("grey57", Rgb24::new(145, 145, 145)),
+    ("gray58", Rgb24::new(148, 148, 148)),
+    ("grey58", Rgb24::new(148, 148, 148)),
+    ("gray59", Rgb24::new(150, 150, 150)),
+    ("grey59", Rgb24::new(150, 150, 150)),
+    ("gray60", Rgb24::new(153, 153, 153)),
+    ("grey60", Rgb24::new(153, 153, 153)),
+    ("gray61", Rgb24::new(156, 156, 156)),
+    ("grey61", Rgb24::new(156, 156, 156)),
+    ("gray62", Rgb24::new(158, 158, 158)),
+    ("grey62", Rgb24::new(158, 158, 158)),
+    ("gray63", Rgb24::new(161, 161, 161)),
+    ("grey63", Rgb24::new(161, 161, 161)),
+    ("gray64", Rgb24::new(163, 163, 163)),
+    ("grey64", Rgb24::new(163, 163, 163)),
+    ("gray65", Rgb24::new(166, 166, 166)),
+    ("grey65", Rgb24::new(166, 166, 166)),
+    ("gray66", Rgb24::new(168, 168, 168)),
+    ("grey66", Rgb24::new(168, 168, 168)),
+    ("gray67", Rgb24::new(171, 171, 171)),
+    ("grey67", Rgb24::new(171, 171, 171)),
+    ("gray68", Rgb24::new(173, 173, 173)),
+    ("grey68", Rgb24::new(173, 173, 173)),
+    ("gray69", Rgb24::new(176, 176, 176)),
+    ("grey69", Rgb24::new(176, 176, 176)),
+    ("gray70", Rgb24::new(179, 179, 179)),
+    ("grey70", Rgb24::new(179, 179, 179)),
+    ("gray71", Rgb24::new(181, 181, 181)),
+    ("grey71", Rgb24::new(181, 181, 181)),
+    ("gray72", Rgb24::new(184, 184, 184)),
+    ("grey72", Rgb24::new(184, 184, 184)),
+    ("gray73", Rgb24::new(186, 186, 186)),
+    ("grey73", Rgb24::new(186, 186, 186)),
+    ("gray74", Rgb24::new(189, 189, 189)),
+    ("grey74", Rgb24::new(189, 189, 189)),
+    ("gray75", Rgb24::new(191, 191, 191)),
+    ("grey75", Rgb24::new(191, 191, 191)),
+    ("gray76", Rgb24::new(194, 194, 194)),
+    ("grey76", Rgb24::new(194, 194, 194)),
+    ("gray77", Rgb24::new(196, 196, 196)),
+    ("grey77", Rgb24::new(196, 196, 196)),
+    ("gray78", Rgb24::new(199, 199, 199)),
+    ("grey78", Rgb24::new(199, 199, 199)),
+    ("gray79", Rgb24::new(201, 201, 201)),
+    ("grey79", Rgb24::new(201, 201, 201)),
+    ("gray80", Rgb24::new(204, 204, 204)),
+    ("grey80", Rgb24::new(204, 204, 204)),
+    ("gray81", Rgb24::new(207, 207, 207)),
+    ("grey81", Rgb24::new(207, 207, 207)),
+    ("gray82", Rgb24::new(209, 209, 209)),
+    ("grey82", Rgb24::new(209, 209, 209)),
+    ("gray83", Rgb24::new(212, 212, 212)),
+    ("grey83", Rgb24::new(212, 212, 212)),
+    ("gray84", Rgb24::new(214, 214, 214)),
+    ("grey84", Rgb24::new(214, 214, 214)),
+    ("gray85", Rgb24::new(217, 217, 217)),
+    ("grey85", Rgb24::new(217, 217, 217)),
+    ("gray86", Rgb24::new(219, 219, 219)),
+    ("grey86", Rgb24::new(219, 219, 219)),
+    ("gray87", Rgb24::new(222, 222, 222)),
+    ("grey87", Rgb24::new(222, 222, 222)),
+    ("gray88", Rgb24::new(224, 224, 224)),
+    ("grey88", Rgb24::new(224, 224, 224)),
+    ("gray89", Rgb24::new(227, 227, 227)),
+    ("grey89", Rgb24::new(227, 227, 227)),
+    ("gray90", Rgb24::new(229, 229, 229)),
+    ("grey90", Rgb24::new(229, 229, 229)),
+    ("gray91", Rgb24::new(232, 232, 232)),
+    ("grey91", Rgb24::new(232, 232, 232)),
+    ("gray92", Rgb24::new(235, 235, 235)),
+    ("grey92", Rgb24::new(235, 235, 235)),
+    ("gray93", Rgb24::new(237, 237, 237)),
+    ("grey93", Rgb24::new(237, 237, 237)),
+    ("gray94", Rgb24::new(240, 240, 240)),
+    ("grey94", Rgb24::new(240, 240, 240)),
+    ("gray95", Rgb24::new(242, 242, 242)),
+    ("grey95", Rgb24::new(242, 242, 242)),
+    ("gray96", Rgb24::new(245, 245, 245)),
+    ("grey96", Rgb24::new(245, 245, 245)),
+    ("gray97", Rgb24::new(247, 247, 247)),
+    ("grey97", Rgb24::new(247, 247, 247)),
+    ("gray98", Rgb24::new(250, 250, 250)),
+    ("grey98", Rgb24::new(250, 250, 250)),
+    ("gray99", Rgb24::new(252, 252, 252)),
+    ("grey99", Rgb24::new(252, 252, 252)),
+    ("gray100", Rgb24::new(255, 255, 255)),
+    ("grey100", Rgb24::new(255, 255, 255)),
+    ("darkgrey", Rgb24::new(169, 169, 169)),
+    ("darkgray", Rgb24::new(169, 169, 169)),
+    ("darkblue", Rgb24::new(0, 0, 139)),
+    ("darkcyan", Rgb24::new(0, 139, 139)),
+    ("darkmagenta", Rgb24::new(139, 0, 139)),
+    ("darkred", Rgb24::new(139, 0, 0)),
+    ("lightgreen", Rgb24::new(144, 238, 144)),
+    ("webgray", Rgb24::new(128, 128, 128)),
+    ("webgrey", Rgb24::new(128, 128, 128)),
+    ("webgreen", Rgb24::new(0, 128, 0)),
+    ("webmaroon", Rgb24::new(128, 0, 0)),
+    ("webpurple", Rgb24::new(128, 0, 128)),
+    ("x11gray", Rgb24::new(190, 190, 190)),
+    ("x11grey", Rgb24::new(190, 190, 190)),
+    ("x11green", Rgb24::new(0, 255, 0)),
+    ("x11maroon", Rgb24::new(176, 48, 96)),
+    ("x11purple", Rgb24::new(160, 32, 240)),
+    ("aqua", Rgb24::new(0, 255, 255)),
+    ("crimson", Rgb24::new(220, 20, 60)),
+    ("fuchsia", Rgb24::new(255, 0, 255)),
+    ("indigo", Rgb24::new(75, 0, 130)),
+    ("lime", Rgb24::new(0, 255, 0)),
+    ("olive", Rgb24::new(128, 128, 0)),
+    ("rebeccapurple", Rgb24::new(102, 51, 153)),
+    ("silver", Rgb24::new(192, 192, 192)),
+    ("teal", Rgb24::new(0, 128, 128)),
+];
+
+/// Collection of named colors for nearest-name lookups.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ColorNames {
+    names: Vec<String>,
+    colors: Vec<Rgb24>,
+    labs: Vec<Lab>,
+}
+
+impl ColorNames {
+    /// Creates an empty collection.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a collection of built-in names.
+    pub fn builtin(set: NameSet) -> Self {
+        let table: &[(&str, Rgb24)] = match set {
+            NameSet::Css => &CSS,
+            NameSet::X11 => &X11,
+        };
+
+        let mut names = Self::new();
+        for &(name, color) in table {
+            names.push(name, color);
+        }
+
+        names
+    }
+
+    /// Parses a list of named colors.
+    ///
+    /// Each line holds a color followed by its name, such as
+    /// `#E4572E Flame`. Empty lines and lines starting with `//` are
+    /// skipped.
+    ///
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut names = Self::new();
+
+        for line in text.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with("//") {
+                continue;
+            }
+
+            let (color, name) = line
+                .split_once(char::is_whitespace)
+                .ok_or_else(|| Error::InvalidPalette(format!("missing color name: {line}")))?;
+            names.push(name.trim(), color.parse()?);
+        }
+
+        Ok(names)
+    }
+
+    /// Adds a named color. A name that is already present is given the new
+    /// color.
+    pub fn push(&mut self, name: &str, color: Rgb24) {
+        match self.names.iter().position(|n| n == name) {
+            Some(i) => {
+                self.colors[i] = color;
+                self.labs[i] = color.to_lab();
+            }
+            None => {
+                self.names.push(name.to_string());
+                self.colors.push(color);
+                self.labs.push(color.to_lab());
+            }
+        }
+    }
+
+    /// Adds the named colors of another collection.
+    pub fn extend(&mut self, other: &Self) {
+        for (name, &color) in other.names.iter().zip(&other.colors) {
+            self.push(name, color);
+        }
+    }
+
+    /// Retrieves the number of named colors.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Checks whether there are no named colors.
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Finds the color of a name.
+    pub fn get(&self, name: &str) -> Option<Rgb24> {
+        let i = self.names.iter().position(|n| n == name)?;
+        Some(self.colors[i])
+    }
+
+    /// Finds the name closest to a color, with its CIE76 Delta E.
+    /// On ties, the name added first wins.
+    pub fn nearest(&self, color: &Rgb24) -> Option<(&str, f32)> {
+        let lab = color.to_lab();
+
+        self.labs
+            .iter()
+            .map(|other| lab.delta_e(other))
+            .enumerate()
+            .min_by(|(a, x), (b, y)| x.total_cmp(y).then(a.cmp(b)))
+            .map(|(i, delta_e)| (self.names[i].as_str(), delta_e))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn names_builtin() {
+        let css = ColorNames::builtin(NameSet::Css);
+        assert_eq!(css.len(), 148);
+        assert_eq!(css.nearest(&Rgb24::new(255, 0, 0)), Some(("red", 0.0)));
+        assert_eq!(css.nearest(&Rgb24::new(0, 255, 255)).unwrap().0, "aqua");
+
+        let (name, delta_e) = css.nearest(&Rgb24::new(250, 5, 3)).unwrap();
+        assert_eq!(name, "red");
+        assert!(delta_e > 0.0 && delta_e < 5.0);
+
+        let x11 = ColorNames::builtin(NameSet::X11);
+        assert_eq!(x11.get("green"), Some(Rgb24::new(0, 255, 0)));
+        assert_eq!(x11.get("webgray"), css.get("gray"));
+        assert_eq!(x11.len(), 677);
+        assert_eq!(x11.get("navyblue"), Some(Rgb24::new(0, 0, 128)));
+        assert_eq!(x11.get("lightgoldenrod"), Some(Rgb24::new(238, 221, 130)));
+        assert_eq!(x11.get("gray100"), Some(Rgb24::new(255, 255, 255)));
+        assert_eq!(x11.get("red4"), Some(Rgb24::new(139, 0, 0)));
+
+        // Base names win over numbered variants of the same color.
+        assert_eq!(x11.nearest(&Rgb24::new(255, 255, 255)).unwrap().0, "white");
+        assert_eq!(x11.nearest(&Rgb24::new(255, 0, 0)).unwrap().0, "red");
+        assert!(ColorNames::new().nearest(&Rgb24::new(0, 0, 0)).is_none());
+    }
+
+    #[test]
+    fn names_parse() {
+        let text = "// Brand colors\n#E4572E Flame\n\n  2E86AB   Steel Blue \n";
+        let names = ColorNames::parse(text).unwrap();
+        assert_eq!(names.len(), 2);
+        assert_eq!(names.get("Steel Blue"), Some(Rgb24::new(46, 134, 171)));
+
+        let mut all = ColorNames::builtin(NameSet::Css);
+        all.extend(&names);
+        assert_eq!(all.nearest(&Rgb24::new(228, 87, 46)), Some(("Flame", 0.0)));
+
+        assert!(ColorNames::parse("#E4572E").is_err());
+        assert!(ColorNames::parse("flame #E4572E").is_err());
+    }
+}
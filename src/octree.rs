@@ -1,4 +1,5 @@
 use crate::color::Rgb24;
+use crate::error::Result;
 
 /// Handle associated with a particular octant.
 type Handle = usize;
@@ -12,7 +13,7 @@ type Size = Index;
 /// Branch octants hold handles to 8 child octants.
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct Branch {
-    pub children: [Handle; 8],
+    pub children: [Handle; Octant::MAX_SIZE],
 }
 
 /// Leaf octants hold summed RGB values.
@@ -31,31 +32,14 @@ enum Octant {
     Leaf(Leaf),
 }
 
-impl From<&Rgb24> for Octant {
-    fn from(color: &Rgb24) -> Self {
-        Self::Leaf(Leaf {
-            count: 1,
-            r: color.r() as u64,
-            g: color.g() as u64,
-            b: color.b() as u64,
-        })
-    }
-}
-
 impl Octant {
-    /// Minimum child index.
-    pub const MIN_CHILD: Index = 0;
-
-    /// Maximum child index.
-    pub const MAX_CHILD: Index = 7;
-
     /// Maximum valid child count.
     pub const MAX_SIZE: Size = 8;
 
     /// Creates a new branch octant.
     pub fn new_branch() -> Self {
         Self::Branch(Branch {
-            children: [Octree::EMPTY; 8],
+            children: [Octree::EMPTY; Octant::MAX_SIZE],
         })
     }
 
@@ -98,15 +82,15 @@ impl Octant {
         }
     }
 
-    /// Adds a color into the octant.
-    pub fn add_color(&mut self, color: &Rgb24) {
+    /// Adds a color into the octant, counted `weight` times.
+    pub fn add_color(&mut self, color: &Rgb24, weight: u64) {
         match self {
             Octant::Branch(_) => (),
             Octant::Leaf(leaf) => {
-                leaf.count += 1;
-                leaf.r += color.r() as u64;
-                leaf.g += color.g() as u64;
-                leaf.b += color.b() as u64;
+                leaf.count += weight;
+                leaf.r += color.r() as u64 * weight;
+                leaf.g += color.g() as u64 * weight;
+                leaf.b += color.b() as u64 * weight;
             }
         }
     }
@@ -135,6 +119,7 @@ impl Octant {
 pub struct Octree {
     octants: Vec<Octant>,
     levels: [Vec<Handle>; 8],
+    depth: usize,
 }
 
 impl Octree {
@@ -144,6 +129,9 @@ impl Octree {
     /// Maximum octant level in an RGB octree.
     const MAX_LEVEL: usize = 8;
 
+    /// Maximum depth of the leaf octants.
+    pub const MAX_DEPTH: usize = Self::MAX_LEVEL;
+
     /// Reserved handle. Used to reference the root octant.
     const ROOT: Handle = 0;
 
@@ -152,9 +140,19 @@ impl Octree {
 
     /// Creates a new RGB octree.
     pub fn new() -> Self {
+        Self::with_depth(Self::MAX_LEVEL)
+    }
+
+    /// Creates a new RGB octree with leaves at the specified depth.
+    ///
+    /// Shallower octrees merge colors sharing their most significant bits
+    /// while building. The depth is clamped to the range [1, 8].
+    ///
+    pub fn with_depth(depth: usize) -> Self {
         Self {
             octants: vec![Octant::new_branch()],
             levels: Default::default(),
+            depth: depth.clamp(Self::MIN_LEVEL + 1, Self::MAX_LEVEL),
         }
     }
 
@@ -178,7 +176,23 @@ impl Default for Octree {
 impl Octree {
     /// Builds the octree from a list of colors.
     pub fn build(&mut self, colors: &[Rgb24]) {
-        colors.iter().for_each(|color| self.add_color(color));
+        self.build_weighted(colors, 1);
+    }
+
+    /// Builds the octree from a list of colors, each counted `weight` times.
+    pub fn build_weighted(&mut self, colors: &[Rgb24], weight: u64) {
+        colors
+            .iter()
+            .for_each(|color| self.add_color(color, weight));
+    }
+
+    /// Builds the octree from colors with individual weights.
+    /// Colors with a zero weight are ignored.
+    pub fn build_histogram(&mut self, pixels: &[(Rgb24, u64)]) {
+        pixels
+            .iter()
+            .filter(|(_, weight)| *weight > 0)
+            .for_each(|(color, weight)| self.add_color(color, *weight));
     }
 
     /// Reduces an octree to the specified number of leaf octants.
@@ -187,10 +201,10 @@ impl Octree {
     /// maintained above the expected size.
     ///
     pub fn into_palette(&mut self, size: usize) -> Vec<Rgb24> {
-        // All leaves are initially stored at the highest level.
-        let mut leaf_count = self.levels[Self::MAX_LEVEL - 1].len();
+        // All leaves are initially stored at the deepest level.
+        let mut leaf_count = self.levels[self.depth - 1].len();
 
-        for &handle in self.levels.iter().rev().skip(1).flatten() {
+        for &handle in self.levels[..self.depth - 1].iter().rev().flatten() {
             let count = self.octants[handle].child_count();
 
             // Reduction not possible, skip to next branch.
@@ -244,18 +258,18 @@ impl Octree {
     }
 
     /// Add a new leaf to an octant.
-    fn add_leaf(&mut self, handle: Handle, index: Index, level: Index, color: &Rgb24) {
+    fn add_leaf(&mut self, handle: Handle, index: Index, level: Index) {
         let leaf_handle = self.make_handle();
-        self.octants.push(Octant::from(color));
+        self.octants.push(Octant::new_leaf(0, 0, 0, 0));
         self.octants[handle].set_child(index, leaf_handle);
         self.levels[level].push(leaf_handle)
     }
 
     /// Adds a color via index traversal.
-    fn add_color(&mut self, color: &Rgb24) {
+    fn add_color(&mut self, color: &Rgb24, weight: u64) {
         let mut handle = Self::ROOT;
 
-        for level in Self::MIN_LEVEL..Self::MAX_LEVEL - 1 {
+        for level in Self::MIN_LEVEL..self.depth - 1 {
             let index = color.level_index(level);
 
             if !self.octants[handle].child_exists(index) {
@@ -265,13 +279,13 @@ impl Octree {
             handle = self.octants[handle].child(index).unwrap();
         }
 
-        let index = color.level_index(Self::MAX_LEVEL - 1);
+        let index = color.level_index(self.depth - 1);
         if !self.octants[handle].child_exists(index) {
-            self.add_leaf(handle, index, Self::MAX_LEVEL - 1, color);
-        } else {
-            let child_handle = self.octants[handle].child(index).unwrap();
-            self.octants[child_handle].add_color(color);
+            self.add_leaf(handle, index, self.depth - 1);
         }
+
+        let child_handle = self.octants[handle].child(index).unwrap();
+        self.octants[child_handle].add_color(color, weight);
     }
 
     /// Creates a new leaf out of a branch by summing child colors.
@@ -293,15 +307,33 @@ impl Octree {
 }
 
 /// Finds a color palette using an RGB octree.
-pub fn octree(colors: &[Rgb24], palette_size: usize) -> Vec<Rgb24> {
-    let mut octree = Octree::new();
+///
+/// Fails if `colors` is empty or `palette_size` is zero.
+///
+pub fn octree(colors: &[Rgb24], palette_size: usize) -> Result<Vec<Rgb24>> {
+    octree_with_depth(colors, palette_size, Octree::MAX_DEPTH)
+}
+
+/// Finds a color palette using an RGB octree of the specified depth.
+///
+/// Fails if `colors` is empty or `palette_size` is zero.
+///
+pub fn octree_with_depth(
+    colors: &[Rgb24],
+    palette_size: usize,
+    depth: usize,
+) -> Result<Vec<Rgb24>> {
+    crate::validate(colors.len(), palette_size)?;
+
+    let mut octree = Octree::with_depth(depth);
     octree.build(colors);
-    octree.into_palette(palette_size)
+    Ok(octree.into_palette(palette_size))
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::error::Error;
 
     #[test]
     fn octree_solve() {
@@ -325,7 +357,7 @@ mod test {
             Rgb24::new(255, 255, 255),
         ];
 
-        let palette = octree(&data, 1);
+        let palette = octree(&data, 1).unwrap();
         let expected = vec![
             Rgb24::new(35, 43, 59),
             Rgb24::new(215, 219, 212),
@@ -333,14 +365,14 @@ mod test {
             Rgb24::new(15, 76, 197),
         ];
         assert_eq!(palette, expected);
-        let palette = octree(&data, 2);
+        let palette = octree(&data, 2).unwrap();
         assert_eq!(palette, expected);
-        let palette = octree(&data, 3);
+        let palette = octree(&data, 3).unwrap();
         assert_eq!(palette, expected);
-        let palette = octree(&data, 4);
+        let palette = octree(&data, 4).unwrap();
         assert_eq!(palette, expected);
 
-        let palette = octree(&data, 5);
+        let palette = octree(&data, 5).unwrap();
         let expected = vec![
             Rgb24::new(35, 43, 59),
             Rgb24::new(215, 219, 212),
@@ -351,4 +383,34 @@ mod test {
         ];
         assert_eq!(palette, expected);
     }
+
+    #[test]
+    fn octree_depth() {
+        let data = vec![
+            Rgb24::new(0, 0, 0),
+            Rgb24::new(10, 20, 30),
+            Rgb24::new(200, 0, 0),
+            Rgb24::new(250, 0, 0),
+            Rgb24::new(0, 0, 255),
+        ];
+
+        let palette = octree_with_depth(&data, 8, 1).unwrap();
+        let expected = vec![
+            Rgb24::new(5, 10, 15),
+            Rgb24::new(225, 0, 0),
+            Rgb24::new(0, 0, 255),
+        ];
+        assert_eq!(palette, expected);
+
+        assert_eq!(octree_with_depth(&data, 8, 8).unwrap().len(), 5);
+    }
+
+    #[test]
+    fn octree_invalid_input() {
+        assert!(matches!(octree(&[], 4), Err(Error::EmptyInput)));
+        assert!(matches!(
+            octree(&[Rgb24::new(1, 2, 3)], 0),
+            Err(Error::InvalidSize(0))
+        ));
+    }
 }
@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+
+use crate::color::Rgb24;
+use crate::error::Result;
+use crate::median_cut::{median_cut, median_cut_histogram};
+use crate::octree::{octree_with_depth, Octree};
+
+/// Palette quantization algorithm.
+///
+/// Implement this trait to run a custom algorithm through [`solve`](crate::solve)
+/// and the rest of paletter.
+///
+pub trait Quantizer {
+    /// Quantizes `colors` into a palette of `palette_size` colors.
+    ///
+    /// `colors` is never empty and `palette_size` is always smaller than
+    /// the number of colors when called through [`solve`](crate::solve).
+    ///
+    fn quantize(&self, colors: Vec<Rgb24>, palette_size: usize) -> Result<Vec<Rgb24>>;
+
+    /// Whether the palette has exactly the requested size whenever there
+    /// are enough distinct colors.
+    fn exact_size(&self) -> bool {
+        true
+    }
+}
+
+impl<Q: Quantizer + ?Sized> Quantizer for &Q {
+    fn quantize(&self, colors: Vec<Rgb24>, palette_size: usize) -> Result<Vec<Rgb24>> {
+        (**self).quantize(colors, palette_size)
+    }
+
+    fn exact_size(&self) -> bool {
+        (**self).exact_size()
+    }
+}
+
+impl<Q: Quantizer + ?Sized> Quantizer for Box<Q> {
+    fn quantize(&self, colors: Vec<Rgb24>, palette_size: usize) -> Result<Vec<Rgb24>> {
+        (**self).quantize(colors, palette_size)
+    }
+
+    fn exact_size(&self) -> bool {
+        (**self).exact_size()
+    }
+}
+
+/// Median cut configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MedianCutOptions {
+    /// Deduplicate colors into a weighted histogram before cutting.
+    ///
+    /// Much faster on large images with few distinct colors. Buckets are
+    /// split at the weighted median, so results differ slightly.
+    ///
+    pub histogram: bool,
+}
+
+impl Quantizer for MedianCutOptions {
+    fn quantize(&self, colors: Vec<Rgb24>, palette_size: usize) -> Result<Vec<Rgb24>> {
+        if !self.histogram {
+            return median_cut(colors, palette_size);
+        }
+
+        let mut histogram = HashMap::new();
+        for color in colors {
+            *histogram.entry(color).or_insert(0) += 1;
+        }
+
+        let mut histogram: Vec<_> = histogram.into_iter().collect();
+        histogram.sort_unstable_by_key(|(c, _)| (c.r(), c.g(), c.b()));
+
+        median_cut_histogram(histogram, palette_size)
+    }
+
+    fn exact_size(&self) -> bool {
+        !self.histogram
+    }
+}
+
+/// Octree configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OctreeOptions {
+    /// Depth of the leaf octants, in the range [1, 8].
+    pub depth: usize,
+}
+
+impl Default for OctreeOptions {
+    fn default() -> Self {
+        Self {
+            depth: Octree::MAX_DEPTH,
+        }
+    }
+}
+
+impl Quantizer for OctreeOptions {
+    fn quantize(&self, colors: Vec<Rgb24>, palette_size: usize) -> Result<Vec<Rgb24>> {
+        octree_with_depth(&colors, palette_size, self.depth)
+    }
+
+    fn exact_size(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::solve;
+
+    /// Quantizer that keeps the first colors.
+    struct Truncate;
+
+    impl Quantizer for Truncate {
+        fn quantize(&self, mut colors: Vec<Rgb24>, palette_size: usize) -> Result<Vec<Rgb24>> {
+            colors.truncate(palette_size);
+            Ok(colors)
+        }
+    }
+
+    #[test]
+    fn quantizer_custom() {
+        let colors = vec![
+            Rgb24::new(1, 2, 3),
+            Rgb24::new(4, 5, 6),
+            Rgb24::new(7, 8, 9),
+        ];
+
+        let quantizer: &dyn Quantizer = &Truncate;
+        let palette = solve(quantizer, colors.clone(), 2).unwrap();
+        assert_eq!(palette, colors[..2]);
+    }
+
+    #[test]
+    fn quantizer_median_cut_histogram() {
+        let colors = vec![
+            Rgb24::new(10, 10, 10),
+            Rgb24::new(10, 10, 10),
+            Rgb24::new(20, 10, 10),
+            Rgb24::new(200, 10, 10),
+        ];
+
+        let options = MedianCutOptions { histogram: true };
+        assert!(!options.exact_size());
+        assert!(MedianCutOptions::default().exact_size());
+
+        let palette = solve(&options, colors, 2).unwrap();
+        assert_eq!(
+            palette,
+            vec![Rgb24::new(10, 10, 10), Rgb24::new(110, 10, 10)]
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+
+use crate::color::Rgb24;
+
+/// Finds the index of the palette color closest to `color`.
+///
+/// Distance is measured in RGB space. Returns `None` if the palette is empty.
+///
+pub fn nearest(palette: &[Rgb24], color: &Rgb24) -> Option<usize> {
+    palette
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, p)| p.distance_squared(color))
+        .map(|(i, _)| i)
+}
+
+/// Maps colors to the index of their nearest palette color.
+///
+/// Lookups are cached, so images with many repeated colors are cheap.
+/// Returns an empty list if the palette is empty.
+///
+pub fn indices(colors: &[Rgb24], palette: &[Rgb24]) -> Vec<usize> {
+    if palette.is_empty() {
+        return vec![];
+    }
+
+    let mut cache = HashMap::new();
+
+    colors
+        .iter()
+        .map(|color| {
+            *cache
+                .entry(*color)
+                .or_insert_with(|| nearest(palette, color).unwrap())
+        })
+        .collect()
+}
+
+/// Replaces each color with its nearest palette color.
+pub fn remap(colors: &[Rgb24], palette: &[Rgb24]) -> Vec<Rgb24> {
+    indices(colors, palette)
+        .into_iter()
+        .map(|i| palette[i])
+        .collect()
+}
+
+/// Counts the number of colors closest to each palette color.
+pub fn population(colors: &[Rgb24], palette: &[Rgb24]) -> Vec<u64> {
+    let mut counts = vec![0; palette.len()];
+    indices(colors, palette)
+        .into_iter()
+        .for_each(|i| counts[i] += 1);
+    counts
+}
+
+/// Replaces each pixel of an image with its nearest palette color.
+/// The alpha channel is preserved.
+#[cfg(feature = "image")]
+pub fn remap_image(img: &image::RgbaImage, palette: &[Rgb24]) -> image::RgbaImage {
+    let colors = crate::rgba_image_to_rgb24(img, u8::MIN);
+    let remapped = remap(&colors, palette);
+
+    let mut out = img.clone();
+    for (px, color) in out.pixels_mut().zip(remapped) {
+        px.0 = [color.r(), color.g(), color.b(), px.0[3]];
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn remap_nearest() {
+        let palette = vec![
+            Rgb24::new(0, 0, 0),
+            Rgb24::new(255, 255, 255),
+            Rgb24::new(255, 0, 0),
+        ];
+
+        let colors = vec![
+            Rgb24::new(10, 20, 5),
+            Rgb24::new(200, 30, 40),
+            Rgb24::new(220, 230, 210),
+            Rgb24::new(10, 20, 5),
+        ];
+
+        assert_eq!(indices(&colors, &palette), vec![0, 2, 1, 0]);
+        assert_eq!(
+            remap(&colors, &palette),
+            vec![palette[0], palette[2], palette[1], palette[0]]
+        );
+        assert_eq!(population(&colors, &palette), vec![2, 1, 1]);
+
+        assert_eq!(nearest(&[], &colors[0]), None);
+        assert!(remap(&colors, &[]).is_empty());
+    }
+}
@@ -0,0 +1,323 @@
+use crate::color::Rgb24;
+use crate::error::Result;
+use crate::quantizer::Quantizer;
+use crate::remap;
+
+/// Role of a palette color in a theme, as in Android's Palette library.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {
+    Vibrant,
+    DarkVibrant,
+    LightVibrant,
+    Muted,
+    DarkMuted,
+    LightMuted,
+    /// The most frequent color.
+    Dominant,
+}
+
+/// Palette color chosen for a role, with readable text colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoleSwatch {
+    pub role: Role,
+    pub color: Rgb24,
+    /// Number of pixels mapped to the color.
+    pub population: u64,
+    /// Text color for titles, with a contrast ratio of at least 3.
+    pub title_text: Rgb24,
+    /// Text color for body text, with a contrast ratio of at least 4.5.
+    pub body_text: Rgb24,
+}
+
+/// Minimum, ideal and maximum values of an HSL component.
+struct Range {
+    min: f32,
+    target: f32,
+    max: f32,
+}
+
+impl Range {
+    const fn new(min: f32, target: f32, max: f32) -> Self {
+        Self { min, target, max }
+    }
+
+    fn contains(&self, value: f32) -> bool {
+        (self.min..=self.max).contains(&value)
+    }
+}
+
+/// Lightness and saturation targets of a role.
+struct Target {
+    role: Role,
+    lightness: Range,
+    saturation: Range,
+}
+
+const LIGHT: Range = Range::new(0.55, 0.74, 1.0);
+const NORMAL: Range = Range::new(0.3, 0.5, 0.7);
+const DARK: Range = Range::new(0.0, 0.26, 0.45);
+const VIBRANT: Range = Range::new(0.35, 1.0, 1.0);
+const MUTED: Range = Range::new(0.0, 0.3, 0.4);
+
+/// Targets in the order roles are assigned. A color fills a single role.
+const TARGETS: [Target; 6] = [
+    Target {
+        role: Role::LightVibrant,
+        lightness: LIGHT,
+        saturation: VIBRANT,
+    },
+    Target {
+        role: Role::Vibrant,
+        lightness: NORMAL,
+        saturation: VIBRANT,
+    },
+    Target {
+        role: Role::DarkVibrant,
+        lightness: DARK,
+        saturation: VIBRANT,
+    },
+    Target {
+        role: Role::LightMuted,
+        lightness: LIGHT,
+        saturation: MUTED,
+    },
+    Target {
+        role: Role::Muted,
+        lightness: NORMAL,
+        saturation: MUTED,
+    },
+    Target {
+        role: Role::DarkMuted,
+        lightness: DARK,
+        saturation: MUTED,
+    },
+];
+
+/// Relative importance of saturation, lightness and population when
+/// scoring a color for a role.
+const SATURATION_WEIGHT: f32 = 0.24;
+const LIGHTNESS_WEIGHT: f32 = 0.52;
+const POPULATION_WEIGHT: f32 = 0.24;
+
+/// Minimum contrast ratios of title and body text.
+const TITLE_CONTRAST: f32 = 3.0;
+const BODY_CONTRAST: f32 = 4.5;
+
+/// Assigns palette colors to roles.
+///
+/// Each color is scored against the HSL lightness and saturation targets of
+/// every role, favoring frequent colors, and fills at most one role besides
+/// [`Role::Dominant`]. Roles without a matching color are left out, so the
+/// result may be shorter than the number of roles. `population` holds the
+/// pixel count of each color; colors are given equal shares if it does not
+/// match the palette.
+///
+pub fn swatches(palette: &[Rgb24], population: &[u64]) -> Vec<RoleSwatch> {
+    let equal = vec![1; palette.len()];
+    let population = match population.len() == palette.len() {
+        true => population,
+        false => &equal,
+    };
+    let largest = population.iter().copied().max().unwrap_or_default().max(1);
+
+    let hsl: Vec<_> = palette.iter().map(hsl).collect();
+    let mut used = vec![false; palette.len()];
+    let mut swatches = Vec::new();
+
+    for target in &TARGETS {
+        let best = (0..palette.len())
+            .filter(|&i| !used[i])
+            .filter(|&i| {
+                let (s, l) = hsl[i];
+                target.saturation.contains(s) && target.lightness.contains(l)
+            })
+            .map(|i| {
+                let (s, l) = hsl[i];
+                let score = SATURATION_WEIGHT * (1.0 - (s - target.saturation.target).abs())
+                    + LIGHTNESS_WEIGHT * (1.0 - (l - target.lightness.target).abs())
+                    + POPULATION_WEIGHT * population[i] as f32 / largest as f32;
+                (i, score)
+            })
+            .max_by(|(_, a), (_, b)| a.total_cmp(b));
+
+        if let Some((i, _)) = best {
+            used[i] = true;
+            swatches.push(swatch(target.role, palette[i], population[i]));
+        }
+    }
+
+    // Earliest color on ties, like a stable sort.
+    let dominant = (0..palette.len()).max_by_key(|&i| (population[i], std::cmp::Reverse(i)));
+    if let Some(i) = dominant {
+        swatches.push(swatch(Role::Dominant, palette[i], population[i]));
+    }
+
+    swatches
+}
+
+/// Quantizes a palette and assigns its colors to roles. See [`swatches`].
+pub fn solve_swatches<Q: Quantizer + ?Sized>(
+    quantizer: &Q,
+    colors: Vec<Rgb24>,
+    palette_size: usize,
+) -> Result<Vec<RoleSwatch>> {
+    let palette = crate::solve(quantizer, colors.clone(), palette_size)?;
+    let population = remap::population(&colors, &palette);
+
+    Ok(swatches(&palette, &population))
+}
+
+/// Creates a swatch with its text colors.
+fn swatch(role: Role, color: Rgb24, population: u64) -> RoleSwatch {
+    let white = Rgb24::new(255, 255, 255);
+    let black = Rgb24::new(0, 0, 0);
+
+    // White text is preferred when it is legible as body text.
+    let text = match min_alpha(white, color, BODY_CONTRAST) {
+        Some(_) => white,
+        None => black,
+    };
+    let text_color = |ratio| match min_alpha(text, color, ratio) {
+        Some(alpha) => blend(text, color, alpha),
+        None => color.contrasting(),
+    };
+
+    RoleSwatch {
+        role,
+        color,
+        population,
+        title_text: text_color(TITLE_CONTRAST),
+        body_text: text_color(BODY_CONTRAST),
+    }
+}
+
+/// Finds the lowest opacity of `text` over `background` that reaches a
+/// contrast ratio, so that text keeps a tint of the background.
+fn min_alpha(text: Rgb24, background: Rgb24, ratio: f32) -> Option<u8> {
+    if text.contrast_ratio(&background) < ratio {
+        return None;
+    }
+
+    // Contrast grows with opacity, so the lowest passing alpha is bisected.
+    let (mut low, mut high) = (0u8, u8::MAX);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if blend(text, background, mid).contrast_ratio(&background) >= ratio {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+
+    Some(high)
+}
+
+/// Composites `fg` with opacity `alpha` over `bg`.
+fn blend(fg: Rgb24, bg: Rgb24, alpha: u8) -> Rgb24 {
+    let mix = |f: u8, b: u8| {
+        let (f, b, a) = (f as u32, b as u32, alpha as u32);
+        ((f * a + b * (255 - a) + 127) / 255) as u8
+    };
+
+    Rgb24::new(
+        mix(fg.r(), bg.r()),
+        mix(fg.g(), bg.g()),
+        mix(fg.b(), bg.b()),
+    )
+}
+
+/// Finds the HSL saturation and lightness of a color.
+fn hsl(color: &Rgb24) -> (f32, f32) {
+    let max = color.r().max(color.g()).max(color.b()) as f32 / 255.0;
+    let min = color.r().min(color.g()).min(color.b()) as f32 / 255.0;
+    let l = (max + min) / 2.0;
+
+    match max == min {
+        true => (0.0, l),
+        false => ((max - min) / (1.0 - (2.0 * l - 1.0).abs()), l),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Method;
+
+    fn role(swatches: &[RoleSwatch], role: Role) -> Option<Rgb24> {
+        swatches.iter().find(|s| s.role == role).map(|s| s.color)
+    }
+
+    #[test]
+    fn roles_swatches() {
+        let palette = [
+            Rgb24::new(230, 40, 40),
+            Rgb24::new(120, 10, 10),
+            Rgb24::new(250, 160, 160),
+            Rgb24::new(128, 110, 110),
+            Rgb24::new(50, 45, 45),
+            Rgb24::new(200, 195, 195),
+        ];
+        let swatches = swatches(&palette, &[10, 20, 30, 40, 50, 60]);
+
+        assert_eq!(role(&swatches, Role::Vibrant), Some(palette[0]));
+        assert_eq!(role(&swatches, Role::DarkVibrant), Some(palette[1]));
+        assert_eq!(role(&swatches, Role::LightVibrant), Some(palette[2]));
+        assert_eq!(role(&swatches, Role::Muted), Some(palette[3]));
+        assert_eq!(role(&swatches, Role::DarkMuted), Some(palette[4]));
+        assert_eq!(role(&swatches, Role::LightMuted), Some(palette[5]));
+        assert_eq!(role(&swatches, Role::Dominant), Some(palette[5]));
+
+        // Grays have no vibrant roles.
+        let grays = [Rgb24::new(20, 20, 20), Rgb24::new(240, 240, 240)];
+        let swatches = super::swatches(&grays, &[]);
+        assert_eq!(role(&swatches, Role::Vibrant), None);
+        assert_eq!(role(&swatches, Role::Dominant), Some(grays[0]));
+        assert!(super::swatches(&[], &[]).is_empty());
+    }
+
+    #[test]
+    fn roles_text_colors() {
+        for color in [
+            Rgb24::new(0, 0, 0),
+            Rgb24::new(255, 255, 255),
+            Rgb24::new(230, 40, 40),
+            Rgb24::new(250, 220, 0),
+        ] {
+            let swatch = swatch(Role::Muted, color, 1);
+            assert!(swatch.title_text.contrast_ratio(&color) >= TITLE_CONTRAST);
+            assert!(swatch.body_text.contrast_ratio(&color) >= BODY_CONTRAST);
+        }
+
+        // Translucent white keeps a tint of dark backgrounds.
+        let swatch = swatch(Role::Muted, Rgb24::new(0, 0, 0), 1);
+        assert!(swatch.title_text.r() < 255);
+    }
+
+    #[test]
+    fn roles_solve() {
+        let mut colors = vec![Rgb24::new(30, 30, 30); 20];
+        colors.extend(vec![Rgb24::new(240, 30, 30); 10]);
+
+        let swatches = solve_swatches(&Method::Octree, colors, 2).unwrap();
+        assert_eq!(
+            role(&swatches, Role::Vibrant),
+            Some(Rgb24::new(240, 30, 30))
+        );
+        assert_eq!(
+            role(&swatches, Role::Dominant),
+            Some(Rgb24::new(30, 30, 30))
+        );
+        assert!(solve_swatches(&Method::MedianCut, vec![], 2).is_err());
+    }
+
+    #[test]
+    fn roles_solve_few_colors() {
+        let red = Rgb24::new(240, 30, 30);
+
+        for method in [Method::MedianCut, Method::Octree] {
+            let swatches = solve_swatches(&method, vec![red; 64], 6).unwrap();
+            assert_eq!(role(&swatches, Role::Vibrant), Some(red));
+            assert_eq!(role(&swatches, Role::Dominant), Some(red));
+        }
+    }
+}
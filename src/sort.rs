@@ -0,0 +1,228 @@
+use crate::color::{Oklab, Rgb24};
+
+/// Palette ordering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum SortOrder {
+    /// Coarse HSV, the `Ord` implementation of `Rgb24`.
+    Hsv,
+    /// HSV hue, with grays first.
+    Hue,
+    /// HSL lightness.
+    Lightness,
+    /// WCAG relative luminance.
+    Luminance,
+    /// HSV saturation.
+    Saturation,
+    /// Pixel count, most frequent first.
+    Frequency,
+    /// Oklch hue, with grays first.
+    Oklch,
+    /// CIE L* lightness.
+    LabL,
+    /// Short path through Oklab starting from the darkest color, so that
+    /// neighbors look alike.
+    Path,
+}
+
+/// Chroma below which a color is considered gray when sorting by Oklch hue.
+const GRAY_CHROMA: f32 = 0.02;
+
+/// Maximum number of improvement passes over the perceptual path.
+const PATH_PASSES: usize = 64;
+
+/// Finds the order of palette colors, as indices into the palette.
+///
+/// `population` holds the pixel count of each color and is only used by
+/// [`SortOrder::Frequency`]. If it does not match the palette, the palette
+/// order is kept. Sorting is stable.
+///
+pub fn sort_indices(palette: &[Rgb24], population: &[u64], order: SortOrder) -> Vec<usize> {
+    let mut indices: Vec<_> = (0..palette.len()).collect();
+
+    // Sorts by a floating-point key of each color.
+    let mut sort_by_key = |key: &dyn Fn(&Rgb24) -> (f32, f32)| {
+        let keys: Vec<_> = palette.iter().map(key).collect();
+        indices.sort_by(|&a, &b| {
+            let (a, b) = (keys[a], keys[b]);
+            a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1))
+        });
+    };
+
+    match order {
+        SortOrder::Hsv => indices.sort_by_key(|&i| palette[i]),
+        SortOrder::Hue => sort_by_key(&|c| {
+            let (h, s, v) = c.hsv();
+            (if s == 0.0 { -1.0 } else { h }, v)
+        }),
+        SortOrder::Lightness => sort_by_key(&|c| (hsl_lightness(c), 0.0)),
+        SortOrder::Luminance => sort_by_key(&|c| (c.relative_luminance(), 0.0)),
+        SortOrder::Saturation => sort_by_key(&|c| {
+            let (_, s, v) = c.hsv();
+            (s, v)
+        }),
+        SortOrder::Frequency => {
+            if population.len() == palette.len() {
+                indices.sort_by_key(|&i| std::cmp::Reverse(population[i]));
+            }
+        }
+        SortOrder::Oklch => sort_by_key(&|c| {
+            let lab = c.to_oklab();
+            let hue = if lab.chroma() < GRAY_CHROMA {
+                -1.0
+            } else {
+                lab.hue()
+            };
+            (hue, lab.l)
+        }),
+        SortOrder::LabL => sort_by_key(&|c| (c.to_lab().l, 0.0)),
+        SortOrder::Path => {
+            let labs: Vec<_> = palette.iter().map(Rgb24::to_oklab).collect();
+            indices = path(&labs);
+        }
+    }
+
+    indices
+}
+
+/// Sorts a palette in place. Frequency order keeps the palette as is.
+pub fn sort(palette: &mut [Rgb24], order: SortOrder) {
+    let sorted: Vec<_> = sort_indices(palette, &[], order)
+        .into_iter()
+        .map(|i| palette[i])
+        .collect();
+
+    palette.copy_from_slice(&sorted);
+}
+
+/// Finds a short open path through colors, starting from the darkest.
+///
+/// A nearest-neighbor tour is refined with 2-opt moves until no reversal
+/// of a segment shortens it.
+///
+fn path(labs: &[Oklab]) -> Vec<usize> {
+    let Some(start) = (0..labs.len()).min_by(|&a, &b| labs[a].l.total_cmp(&labs[b].l)) else {
+        return vec![];
+    };
+
+    let dist = |a: usize, b: usize| labs[a].distance(&labs[b]);
+
+    // Nearest-neighbor tour.
+    let mut tour = vec![start];
+    let mut left: Vec<_> = (0..labs.len()).filter(|&i| i != start).collect();
+
+    while !left.is_empty() {
+        let last = *tour.last().unwrap();
+        let (pos, _) = left
+            .iter()
+            .enumerate()
+            .min_by(|(_, &a), (_, &b)| dist(last, a).total_cmp(&dist(last, b)))
+            .unwrap();
+        tour.push(left.swap_remove(pos));
+    }
+
+    // 2-opt: reverse tour[i + 1..=j] when it shortens the path. The end of
+    // the path is open, so the last segment has no outgoing edge.
+    for _ in 0..PATH_PASSES {
+        let mut improved = false;
+
+        for i in 0..tour.len().saturating_sub(2) {
+            for j in i + 2..tour.len() {
+                let before = dist(tour[i], tour[i + 1]);
+                let after = dist(tour[i], tour[j]);
+                let (before, after) = match tour.get(j + 1) {
+                    Some(&next) => (
+                        before + dist(tour[j], next),
+                        after + dist(tour[i + 1], next),
+                    ),
+                    None => (before, after),
+                };
+
+                if after < before - f32::EPSILON {
+                    tour[i + 1..=j].reverse();
+                    improved = true;
+                }
+            }
+        }
+
+        if !improved {
+            break;
+        }
+    }
+
+    tour
+}
+
+/// Finds the HSL lightness of a color.
+fn hsl_lightness(color: &Rgb24) -> f32 {
+    let max = color.r().max(color.g()).max(color.b()) as f32;
+    let min = color.r().min(color.g()).min(color.b()) as f32;
+    (max + min) / 2.0 / 255.0
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn palette() -> Vec<Rgb24> {
+        vec![
+            Rgb24::new(0, 0, 255),
+            Rgb24::new(255, 255, 255),
+            Rgb24::new(255, 0, 0),
+            Rgb24::new(0, 0, 0),
+            Rgb24::new(0, 255, 0),
+        ]
+    }
+
+    #[test]
+    fn sort_orders() {
+        let palette = palette();
+
+        assert_eq!(
+            sort_indices(&palette, &[], SortOrder::Hue),
+            vec![3, 1, 2, 4, 0]
+        );
+        assert_eq!(
+            sort_indices(&palette, &[], SortOrder::Luminance),
+            vec![3, 0, 2, 4, 1]
+        );
+        assert_eq!(
+            sort_indices(&palette, &[], SortOrder::Lightness),
+            vec![3, 0, 2, 4, 1]
+        );
+        assert_eq!(
+            sort_indices(&palette, &[], SortOrder::LabL),
+            vec![3, 0, 2, 4, 1]
+        );
+        assert_eq!(
+            sort_indices(&palette, &[1, 5, 3, 5, 0], SortOrder::Frequency),
+            vec![1, 3, 2, 0, 4]
+        );
+        assert_eq!(
+            sort_indices(&palette, &[], SortOrder::Frequency),
+            vec![0, 1, 2, 3, 4]
+        );
+
+        let mut sorted = palette.clone();
+        sort(&mut sorted, SortOrder::Saturation);
+        assert_eq!(&sorted[..2], &[palette[3], palette[1]]);
+    }
+
+    #[test]
+    fn sort_path() {
+        // A shuffled gradient is put back in order.
+        let gradient: Vec<_> = (0..16)
+            .map(|i| Rgb24::new(i * 16, i * 12, 64 + i * 8))
+            .collect();
+        let mut shuffled = gradient.clone();
+        shuffled.swap(0, 9);
+        shuffled.swap(3, 14);
+        shuffled.swap(5, 7);
+
+        let mut sorted = shuffled;
+        sort(&mut sorted, SortOrder::Path);
+        assert_eq!(sorted, gradient);
+
+        assert!(sort_indices(&[], &[], SortOrder::Path).is_empty());
+    }
+}
@@ -0,0 +1,332 @@
+use std::fmt::Write;
+
+use crate::color::Rgb24;
+
+/// Arrangement of the colors in a swatch image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum SwatchLayout {
+    /// A single row of equal cells.
+    #[default]
+    Strip,
+    /// Rows of equal cells.
+    Grid,
+    /// One row per color, with a bar sized by its pixel share.
+    Bars,
+}
+
+/// Options for rendering swatch images.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SwatchOptions {
+    /// Arrangement of the colors.
+    pub layout: SwatchLayout,
+    /// Side of a color cell in pixels.
+    pub cell_size: u32,
+    /// Number of cells per row in the grid layout.
+    pub columns: usize,
+    /// Write the hex code of each color on its cell.
+    pub labels: bool,
+}
+
+impl Default for SwatchOptions {
+    fn default() -> Self {
+        Self {
+            layout: SwatchLayout::Strip,
+            cell_size: 64,
+            columns: 8,
+            labels: false,
+        }
+    }
+}
+
+/// Length of the longest bar in the bars layout, in cells.
+const BAR_CELLS: u32 = 8;
+
+/// Filled rectangle of a swatch, optionally labeled with its hex code.
+struct Rect {
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+    color: Rgb24,
+    label: bool,
+}
+
+/// Computes the size of a swatch and the rectangles it is made of.
+///
+/// In the bars layout, each color gets a labeled cell followed by a bar
+/// proportional to its population. Colors are given equal shares if the
+/// population does not match the palette.
+///
+fn layout(palette: &[Rgb24], population: &[u64], options: &SwatchOptions) -> (u32, u32, Vec<Rect>) {
+    let cell = options.cell_size.max(1);
+    let count = palette.len() as u32;
+
+    let cell_at = |i: u32, columns: u32, color: Rgb24| Rect {
+        x: i % columns * cell,
+        y: i / columns * cell,
+        width: cell,
+        height: cell,
+        color,
+        label: options.labels,
+    };
+
+    match options.layout {
+        SwatchLayout::Strip => {
+            let rects = (0..).zip(palette).map(|(i, &c)| cell_at(i, count, c));
+            (count * cell, cell, rects.collect())
+        }
+        SwatchLayout::Grid => {
+            let columns = (options.columns.max(1) as u32).min(count.max(1));
+            let rows = count.div_ceil(columns);
+            let rects = (0..).zip(palette).map(|(i, &c)| cell_at(i, columns, c));
+            (columns * cell, rows * cell, rects.collect())
+        }
+        SwatchLayout::Bars => {
+            let equal = vec![1; palette.len()];
+            let population = match population.len() == palette.len() {
+                true => population,
+                false => &equal,
+            };
+            let largest = population.iter().copied().max().unwrap_or_default().max(1);
+
+            let mut rects = Vec::new();
+            for (i, (&color, &n)) in (0..).zip(palette.iter().zip(population)) {
+                rects.push(cell_at(i, 1, color));
+
+                let length = (BAR_CELLS * cell) as f64 * n as f64 / largest as f64;
+                rects.push(Rect {
+                    x: cell,
+                    y: i * cell,
+                    width: length.round() as u32,
+                    height: cell,
+                    color,
+                    label: false,
+                });
+            }
+
+            ((BAR_CELLS + 1) * cell, count * cell, rects)
+        }
+    }
+}
+
+/// Renders a palette as an SVG document.
+///
+/// `population` is only used by the bars layout and may be empty otherwise.
+///
+pub fn render_svg(palette: &[Rgb24], population: &[u64], options: &SwatchOptions) -> String {
+    let (width, height, rects) = layout(palette, population, options);
+    let font_size = options.cell_size as f32 / 5.0;
+
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#
+    );
+
+    for rect in rects.iter().filter(|rect| rect.width > 0) {
+        let _ = writeln!(
+            out,
+            r#"  <rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+            rect.x,
+            rect.y,
+            rect.width,
+            rect.height,
+            rect.color.to_hex_string()
+        );
+
+        if rect.label {
+            let _ = writeln!(
+                out,
+                r#"  <text x="{}" y="{}" fill="{}" font-family="monospace" font-size="{font_size}" text-anchor="middle" dominant-baseline="central">{}</text>"#,
+                rect.x as f32 + rect.width as f32 / 2.0,
+                rect.y as f32 + rect.height as f32 / 2.0,
+                rect.color.contrasting().to_hex_string(),
+                rect.color.to_hex_string()
+            );
+        }
+    }
+
+    out.push_str("</svg>\n");
+    out
+}
+
+/// Renders a palette as an image with a transparent background.
+///
+/// `population` is only used by the bars layout and may be empty otherwise.
+///
+#[cfg(feature = "image")]
+pub fn render_image(
+    palette: &[Rgb24],
+    population: &[u64],
+    options: &SwatchOptions,
+) -> image::RgbaImage {
+    let (width, height, rects) = layout(palette, population, options);
+    let mut img = image::RgbaImage::new(width, height);
+
+    for rect in &rects {
+        let px = image::Rgba([rect.color.r(), rect.color.g(), rect.color.b(), u8::MAX]);
+        fill(&mut img, rect.x, rect.y, rect.width, rect.height, px);
+
+        if rect.label {
+            let text = rect.color.to_hex_string();
+            let color = rect.color.contrasting();
+            let px = image::Rgba([color.r(), color.g(), color.b(), u8::MAX]);
+
+            // Largest integer scale at which the label fits in the cell.
+            let scale = ((rect.width + 1) / (text.len() as u32 * GLYPH_ADVANCE)).max(1);
+            let text_width = (text.len() as u32 * GLYPH_ADVANCE - 1) * scale;
+            let x = rect.x + rect.width.saturating_sub(text_width) / 2;
+            let y = rect.y + rect.height.saturating_sub(GLYPH_HEIGHT * scale) / 2;
+
+            for (i, ch) in (0..).zip(text.chars()) {
+                draw_glyph(&mut img, ch, x + i * GLYPH_ADVANCE * scale, y, scale, px);
+            }
+        }
+    }
+
+    img
+}
+
+/// Height of a label glyph in font pixels.
+#[cfg(feature = "image")]
+const GLYPH_HEIGHT: u32 = 5;
+
+/// Horizontal distance between label glyphs in font pixels.
+#[cfg(feature = "image")]
+const GLYPH_ADVANCE: u32 = 4;
+
+/// Rows of a 3x5 bitmap glyph for the characters of a hex code, most
+/// significant bit on the left.
+#[cfg(feature = "image")]
+fn glyph(ch: char) -> [u8; 5] {
+    match ch {
+        '0' => [0b111, 0b101, 0b101, 0b101, 0b111],
+        '1' => [0b010, 0b110, 0b010, 0b010, 0b111],
+        '2' => [0b111, 0b001, 0b111, 0b100, 0b111],
+        '3' => [0b111, 0b001, 0b111, 0b001, 0b111],
+        '4' => [0b101, 0b101, 0b111, 0b001, 0b001],
+        '5' => [0b111, 0b100, 0b111, 0b001, 0b111],
+        '6' => [0b111, 0b100, 0b111, 0b101, 0b111],
+        '7' => [0b111, 0b001, 0b010, 0b010, 0b010],
+        '8' => [0b111, 0b101, 0b111, 0b101, 0b111],
+        '9' => [0b111, 0b101, 0b111, 0b001, 0b111],
+        'A' => [0b010, 0b101, 0b111, 0b101, 0b101],
+        'B' => [0b110, 0b101, 0b110, 0b101, 0b110],
+        'C' => [0b011, 0b100, 0b100, 0b100, 0b011],
+        'D' => [0b110, 0b101, 0b101, 0b101, 0b110],
+        'E' => [0b111, 0b100, 0b110, 0b100, 0b111],
+        'F' => [0b111, 0b100, 0b110, 0b100, 0b100],
+        '#' => [0b101, 0b111, 0b101, 0b111, 0b101],
+        _ => [0; 5],
+    }
+}
+
+/// Draws a scaled glyph with its top-left corner at `(x, y)`.
+#[cfg(feature = "image")]
+fn draw_glyph(
+    img: &mut image::RgbaImage,
+    ch: char,
+    x: u32,
+    y: u32,
+    scale: u32,
+    px: image::Rgba<u8>,
+) {
+    for (row, bits) in (0..).zip(glyph(ch)) {
+        for col in 0..3 {
+            if bits & (0b100 >> col) != 0 {
+                fill(img, x + col * scale, y + row * scale, scale, scale, px);
+            }
+        }
+    }
+}
+
+/// Fills a rectangle, clipped to the image bounds.
+#[cfg(feature = "image")]
+fn fill(img: &mut image::RgbaImage, x: u32, y: u32, width: u32, height: u32, px: image::Rgba<u8>) {
+    let right = (x + width).min(img.width());
+    let bottom = (y + height).min(img.height());
+
+    for y in y..bottom {
+        for x in x..right {
+            img.put_pixel(x, y, px);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn palette() -> Vec<Rgb24> {
+        vec![
+            Rgb24::new(0, 0, 0),
+            Rgb24::new(255, 128, 0),
+            Rgb24::new(255, 255, 255),
+        ]
+    }
+
+    #[test]
+    fn swatch_layout() {
+        let mut options = SwatchOptions {
+            cell_size: 10,
+            columns: 2,
+            ..Default::default()
+        };
+
+        let (width, height, rects) = layout(&palette(), &[], &options);
+        assert_eq!((width, height), (30, 10));
+        assert_eq!((rects[2].x, rects[2].y), (20, 0));
+
+        options.layout = SwatchLayout::Grid;
+        let (width, height, rects) = layout(&palette(), &[], &options);
+        assert_eq!((width, height), (20, 20));
+        assert_eq!((rects[2].x, rects[2].y), (0, 10));
+
+        options.layout = SwatchLayout::Bars;
+        let (width, height, rects) = layout(&palette(), &[4, 2, 0], &options);
+        assert_eq!((width, height), (90, 30));
+        let bars: Vec<_> = rects.iter().skip(1).step_by(2).map(|r| r.width).collect();
+        assert_eq!(bars, vec![80, 40, 0]);
+    }
+
+    #[test]
+    fn swatch_svg() {
+        let options = SwatchOptions {
+            labels: true,
+            ..Default::default()
+        };
+
+        let svg = render_svg(&palette(), &[], &options);
+        assert!(svg.starts_with("<svg") && svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<rect").count(), 3);
+        let label = |hex| {
+            svg.lines()
+                .find(|line| line.ends_with(&format!(">{hex}</text>")))
+        };
+        assert!(label("#000000").unwrap().contains(r##"fill="#FFFFFF""##));
+        assert!(label("#FF8000").unwrap().contains(r##"fill="#000000""##));
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn swatch_image() {
+        let options = SwatchOptions {
+            cell_size: 32,
+            labels: true,
+            ..Default::default()
+        };
+
+        let img = render_image(&palette(), &[], &options);
+        assert_eq!(img.dimensions(), (96, 32));
+        assert_eq!(img.get_pixel(0, 0).0, [0, 0, 0, 255]);
+        assert_eq!(img.get_pixel(95, 31).0, [255, 255, 255, 255]);
+
+        // The label is drawn in white on the black cell.
+        let white = img
+            .enumerate_pixels()
+            .filter(|(x, _, px)| *x < 32 && px.0 == [255, 255, 255, 255])
+            .count();
+        assert!(white > 0);
+    }
+}
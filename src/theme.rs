@@ -0,0 +1,513 @@
+use std::fmt::Write;
+
+use crate::color::{Oklab, Rgb24};
+
+/// Terminal emulator or settings format of a theme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum ThemeTarget {
+    /// Alacritty TOML colors.
+    Alacritty,
+    /// kitty.conf colors.
+    Kitty,
+    /// WezTerm TOML color scheme.
+    Wezterm,
+    /// X resources for xterm, URxvt and others.
+    Xresources,
+    /// foot.ini colors.
+    Foot,
+    /// Windows Terminal JSON color scheme.
+    WindowsTerminal,
+    /// iTerm2 `.itermcolors` property list.
+    Iterm2,
+}
+
+/// Terminal color scheme: background, foreground, cursor and the 16 ANSI
+/// colors, the 8 normal ones followed by their bright variants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    pub background: Rgb24,
+    pub foreground: Rgb24,
+    pub cursor: Rgb24,
+    /// Color of the character under the cursor.
+    pub cursor_text: Rgb24,
+    pub ansi: [Rgb24; 16],
+}
+
+/// Names of the ANSI colors, in slot order.
+pub const ANSI_NAMES: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+/// Oklch hues of ANSI colors 1 to 6, from red to cyan.
+const ACCENT_HUES: [f32; 6] = [29.0, 142.0, 110.0, 264.0, 328.0, 195.0];
+
+/// Chroma above which a palette color can stand for an ANSI color.
+const MIN_CHROMA: f32 = 0.04;
+
+/// Largest hue difference in degrees at which a palette color is used for
+/// an ANSI color as is. Farther hues are synthesized instead.
+const HUE_TOLERANCE: f32 = 40.0;
+
+/// Chroma of synthesized colors when the palette has no chromatic colors.
+const DEFAULT_CHROMA: f32 = 0.12;
+
+/// Chroma of the background, foreground and grays, which keep a tint of
+/// the palette.
+const NEUTRAL_CHROMA: f32 = 0.02;
+
+/// Lightness difference between normal and bright ANSI colors.
+const BRIGHT_STEP: f32 = 0.08;
+
+/// Minimum contrast ratios on the background of the foreground, of the
+/// ANSI colors and of the dimmed bright black.
+const TEXT_CONTRAST: f32 = 7.0;
+const ACCENT_CONTRAST: f32 = 4.5;
+const DIM_CONTRAST: f32 = 3.0;
+
+impl Theme {
+    /// Maps palette colors onto a terminal color scheme.
+    ///
+    /// The darkest color becomes the background and the lightest one the
+    /// foreground, or the other way around for `light` themes. ANSI colors
+    /// use the palette colors closest in Oklch hue to red, green and so on,
+    /// or a synthesized color of that hue when the palette has none. Text
+    /// colors are lightened or darkened until they contrast enough with the
+    /// background.
+    pub fn from_palette(palette: &[Rgb24], light: bool) -> Self {
+        // Colors are compared by exact value, as the HSV order of `Rgb24`
+        // would leave some duplicates apart.
+        let mut unique = palette.to_vec();
+        unique.sort_unstable_by_key(|c| (c.r(), c.g(), c.b()));
+        unique.dedup();
+
+        let labs: Vec<_> = unique.iter().map(Rgb24::to_oklab).collect();
+        let darkest = labs.iter().min_by(|a, b| a.l.total_cmp(&b.l)).copied();
+        let lightest = labs.iter().max_by(|a, b| a.l.total_cmp(&b.l)).copied();
+
+        let gray = |l| Oklab::from_lch(l, 0.0, 0.0);
+        let (back, front) = match light {
+            false => (darkest.unwrap_or(gray(0.2)), lightest.unwrap_or(gray(0.9))),
+            true => (
+                lightest.unwrap_or(gray(0.96)),
+                darkest.unwrap_or(gray(0.25)),
+            ),
+        };
+
+        let tint = (back.chroma().min(NEUTRAL_CHROMA), back.hue());
+        let back_l = match light {
+            false => back.l.min(0.2),
+            true => back.l.max(0.96),
+        };
+        let background = fit(back_l, tint.0, tint.1);
+
+        // Moves a color away from the background until it is readable.
+        let readable = |l: f32, chroma: f32, hue: f32, ratio: f32| {
+            let step = if light { -0.01 } else { 0.01 };
+            let mut l = l.clamp(0.0, 1.0);
+            loop {
+                let color = fit(l, chroma, hue);
+                let last = !(0.0..=1.0).contains(&(l + step));
+                if last || color.contrast_ratio(&background) >= ratio {
+                    return color;
+                }
+                l += step;
+            }
+        };
+        let neutral = |l: f32, ratio: f32| readable(l, tint.0, tint.1, ratio);
+
+        let front_l = match light {
+            false => front.l.max(0.85),
+            true => front.l.min(0.3),
+        };
+        let foreground = readable(
+            front_l,
+            front.chroma().min(NEUTRAL_CHROMA),
+            front.hue(),
+            TEXT_CONTRAST,
+        );
+
+        let accents: Vec<_> = labs
+            .iter()
+            .filter(|lab| lab.chroma() >= MIN_CHROMA)
+            .collect();
+        let chroma = match accents.is_empty() {
+            true => DEFAULT_CHROMA,
+            false => accents.iter().map(|lab| lab.chroma()).sum::<f32>() / accents.len() as f32,
+        };
+        let (accent_min, accent_max) = if light { (0.35, 0.6) } else { (0.6, 0.85) };
+
+        let mut ansi = [Rgb24::new(0, 0, 0); 16];
+
+        // Closest hues are matched first, and each palette color is used
+        // for a single ANSI color.
+        let mut matches: Vec<_> = (0..ACCENT_HUES.len())
+            .flat_map(|slot| (0..accents.len()).map(move |j| (slot, j)))
+            .map(|(slot, j)| (slot, j, hue_distance(accents[j].hue(), ACCENT_HUES[slot])))
+            .filter(|(_, _, distance)| *distance <= HUE_TOLERANCE)
+            .collect();
+        matches.sort_by(|(_, _, a), (_, _, b)| a.total_cmp(b));
+
+        let mut matched = [None; ACCENT_HUES.len()];
+        let mut used = vec![false; accents.len()];
+        for (slot, j, _) in matches {
+            if matched[slot].is_none() && !used[j] {
+                matched[slot] = Some(accents[j]);
+                used[j] = true;
+            }
+        }
+
+        for (i, (&hue, closest)) in (1..).zip(ACCENT_HUES.iter().zip(matched)) {
+            let (l, c, h) = match closest {
+                Some(lab) => (lab.l, lab.chroma(), lab.hue()),
+                None => ((accent_min + accent_max) / 2.0, chroma, hue),
+            };
+            let l = l.clamp(accent_min, accent_max);
+            let bright = if light {
+                l - BRIGHT_STEP
+            } else {
+                l + BRIGHT_STEP
+            };
+
+            ansi[i] = readable(l, c, h, ACCENT_CONTRAST);
+            ansi[i + 8] = readable(bright, c, h, ACCENT_CONTRAST);
+        }
+
+        let (black, white) = match light {
+            false => (
+                fit(back_l + 0.1, tint.0, tint.1),
+                neutral(0.8, ACCENT_CONTRAST),
+            ),
+            true => (neutral(0.25, ACCENT_CONTRAST), fit(0.85, tint.0, tint.1)),
+        };
+        ansi[0] = black;
+        ansi[7] = white;
+        ansi[8] = neutral(0.5, DIM_CONTRAST);
+        ansi[15] = match light {
+            false => foreground,
+            true => neutral(0.95, DIM_CONTRAST),
+        };
+
+        // The cursor stands out in the most colorful palette color.
+        let cursor = accents
+            .iter()
+            .max_by(|a, b| a.chroma().total_cmp(&b.chroma()))
+            .map(|lab| readable(lab.l, lab.chroma(), lab.hue(), ACCENT_CONTRAST))
+            .unwrap_or(foreground);
+
+        Self {
+            background,
+            foreground,
+            cursor,
+            cursor_text: background,
+            ansi,
+        }
+    }
+}
+
+/// Finds the difference between two hues in degrees, from 0 to 180.
+fn hue_distance(a: f32, b: f32) -> f32 {
+    let d = (a - b).rem_euclid(360.0);
+    d.min(360.0 - d)
+}
+
+/// Converts an Oklch color to RGB, reducing its chroma until it fits in
+/// the sRGB gamut.
+fn fit(l: f32, chroma: f32, hue: f32) -> Rgb24 {
+    let mut chroma = chroma;
+    let mut lab = Oklab::from_lch(l, chroma, hue);
+
+    while !lab.in_gamut() && chroma > 0.001 {
+        chroma *= 0.9;
+        lab = Oklab::from_lch(l, chroma, hue);
+    }
+
+    lab.to_rgb24()
+}
+
+impl ThemeTarget {
+    /// Writes a theme in the format of the target. `name` is used by the
+    /// formats that name their color schemes.
+    pub fn render(self, theme: &Theme, name: &str) -> String {
+        match self {
+            ThemeTarget::Alacritty => alacritty(theme),
+            ThemeTarget::Kitty => kitty(theme),
+            ThemeTarget::Wezterm => wezterm(theme, name),
+            ThemeTarget::Xresources => xresources(theme),
+            ThemeTarget::Foot => foot(theme),
+            ThemeTarget::WindowsTerminal => windows_terminal(theme, name),
+            ThemeTarget::Iterm2 => iterm2(theme),
+        }
+    }
+}
+
+fn alacritty(theme: &Theme) -> String {
+    let mut out = String::new();
+    let hex = Rgb24::to_hex_string;
+
+    let _ = writeln!(out, "[colors.primary]");
+    let _ = writeln!(out, "background = \"{}\"", hex(&theme.background));
+    let _ = writeln!(out, "foreground = \"{}\"", hex(&theme.foreground));
+    let _ = writeln!(out, "\n[colors.cursor]");
+    let _ = writeln!(out, "text = \"{}\"", hex(&theme.cursor_text));
+    let _ = writeln!(out, "cursor = \"{}\"", hex(&theme.cursor));
+
+    for (section, colors) in [("normal", &theme.ansi[..8]), ("bright", &theme.ansi[8..])] {
+        let _ = writeln!(out, "\n[colors.{section}]");
+        for (name, color) in ANSI_NAMES.iter().zip(colors) {
+            let _ = writeln!(out, "{name} = \"{}\"", hex(color));
+        }
+    }
+
+    out
+}
+
+fn kitty(theme: &Theme) -> String {
+    let mut out = String::new();
+    let hex = Rgb24::to_hex_string;
+
+    let _ = writeln!(out, "background {}", hex(&theme.background));
+    let _ = writeln!(out, "foreground {}", hex(&theme.foreground));
+    let _ = writeln!(out, "cursor {}", hex(&theme.cursor));
+    let _ = writeln!(out, "cursor_text_color {}", hex(&theme.cursor_text));
+    for (i, color) in theme.ansi.iter().enumerate() {
+        let _ = writeln!(out, "color{i} {}", hex(color));
+    }
+
+    out
+}
+
+fn wezterm(theme: &Theme, name: &str) -> String {
+    let mut out = String::new();
+    let hex = Rgb24::to_hex_string;
+    let list = |colors: &[Rgb24]| {
+        let colors: Vec<_> = colors.iter().map(|c| format!("\"{}\"", hex(c))).collect();
+        format!("[{}]", colors.join(", "))
+    };
+
+    let _ = writeln!(out, "[colors]");
+    let _ = writeln!(out, "background = \"{}\"", hex(&theme.background));
+    let _ = writeln!(out, "foreground = \"{}\"", hex(&theme.foreground));
+    let _ = writeln!(out, "cursor_bg = \"{}\"", hex(&theme.cursor));
+    let _ = writeln!(out, "cursor_border = \"{}\"", hex(&theme.cursor));
+    let _ = writeln!(out, "cursor_fg = \"{}\"", hex(&theme.cursor_text));
+    let _ = writeln!(out, "ansi = {}", list(&theme.ansi[..8]));
+    let _ = writeln!(out, "brights = {}", list(&theme.ansi[8..]));
+    let _ = writeln!(out, "\n[metadata]");
+    let _ = writeln!(out, "name = \"{}\"", escape_quotes(name));
+
+    out
+}
+
+fn xresources(theme: &Theme) -> String {
+    let mut out = String::new();
+    let hex = Rgb24::to_hex_string;
+
+    let _ = writeln!(out, "*.background: {}", hex(&theme.background));
+    let _ = writeln!(out, "*.foreground: {}", hex(&theme.foreground));
+    let _ = writeln!(out, "*.cursorColor: {}", hex(&theme.cursor));
+    for (i, color) in theme.ansi.iter().enumerate() {
+        let _ = writeln!(out, "*.color{i}: {}", hex(color));
+    }
+
+    out
+}
+
+fn foot(theme: &Theme) -> String {
+    let mut out = String::new();
+    // foot colors are written without the leading `#`.
+    let hex = |color: &Rgb24| color.to_hex_string()[1..].to_string();
+
+    let _ = writeln!(out, "[cursor]");
+    let _ = writeln!(
+        out,
+        "color={} {}",
+        hex(&theme.cursor_text),
+        hex(&theme.cursor)
+    );
+    let _ = writeln!(out, "\n[colors]");
+    let _ = writeln!(out, "background={}", hex(&theme.background));
+    let _ = writeln!(out, "foreground={}", hex(&theme.foreground));
+    for (i, color) in theme.ansi[..8].iter().enumerate() {
+        let _ = writeln!(out, "regular{i}={}", hex(color));
+    }
+    for (i, color) in theme.ansi[8..].iter().enumerate() {
+        let _ = writeln!(out, "bright{i}={}", hex(color));
+    }
+
+    out
+}
+
+fn windows_terminal(theme: &Theme, name: &str) -> String {
+    // Windows Terminal calls magenta purple.
+    const NAMES: [&str; 8] = [
+        "black", "red", "green", "yellow", "blue", "purple", "cyan", "white",
+    ];
+
+    let hex = Rgb24::to_hex_string;
+    let mut fields = vec![
+        ("name".to_string(), escape_quotes(name)),
+        ("background".to_string(), hex(&theme.background)),
+        ("foreground".to_string(), hex(&theme.foreground)),
+        ("cursorColor".to_string(), hex(&theme.cursor)),
+        ("selectionBackground".to_string(), hex(&theme.ansi[8])),
+    ];
+
+    for (i, color) in theme.ansi.iter().enumerate() {
+        let name = NAMES[i % 8];
+        let key = match i < 8 {
+            true => name.to_string(),
+            false => format!("bright{}{}", name[..1].to_uppercase(), &name[1..]),
+        };
+        fields.push((key, hex(color)));
+    }
+
+    let fields: Vec<_> = fields
+        .iter()
+        .map(|(key, value)| format!("    \"{key}\": \"{value}\""))
+        .collect();
+
+    format!("{{\n{}\n}}\n", fields.join(",\n"))
+}
+
+fn iterm2(theme: &Theme) -> String {
+    let mut out = String::new();
+
+    out.push_str(concat!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+        "<!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n",
+        "<plist version=\"1.0\">\n",
+        "<dict>\n",
+    ));
+
+    let mut colors: Vec<_> = (0..)
+        .zip(&theme.ansi)
+        .map(|(i, color)| (format!("Ansi {i} Color"), *color))
+        .collect();
+    colors.extend([
+        ("Background Color".to_string(), theme.background),
+        ("Foreground Color".to_string(), theme.foreground),
+        ("Cursor Color".to_string(), theme.cursor),
+        ("Cursor Text Color".to_string(), theme.cursor_text),
+    ]);
+
+    for (key, color) in colors {
+        let _ = writeln!(out, "\t<key>{key}</key>\n\t<dict>");
+        let _ = writeln!(out, "\t\t<key>Alpha Component</key>\n\t\t<real>1</real>");
+        for (channel, value) in [
+            ("Blue", color.b()),
+            ("Green", color.g()),
+            ("Red", color.r()),
+        ] {
+            let _ = writeln!(
+                out,
+                "\t\t<key>{channel} Component</key>\n\t\t<real>{:.6}</real>",
+                value as f64 / 255.0
+            );
+        }
+        let _ = writeln!(out, "\t\t<key>Color Space</key>\n\t\t<string>sRGB</string>");
+        let _ = writeln!(out, "\t</dict>");
+    }
+
+    out.push_str("</dict>\n</plist>\n");
+    out
+}
+
+/// Escapes backslashes and double quotes in a quoted string.
+fn escape_quotes(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn palette() -> Vec<Rgb24> {
+        vec![
+            Rgb24::new(20, 24, 30),
+            Rgb24::new(230, 225, 215),
+            Rgb24::new(200, 60, 50),
+            Rgb24::new(60, 150, 70),
+            Rgb24::new(50, 90, 200),
+        ]
+    }
+
+    #[test]
+    fn theme_from_palette() {
+        let theme = Theme::from_palette(&palette(), false);
+
+        assert!(theme.background.relative_luminance() < 0.05);
+        assert!(theme.foreground.contrast_ratio(&theme.background) >= TEXT_CONTRAST);
+        for color in &theme.ansi[1..7] {
+            assert!(color.contrast_ratio(&theme.background) >= ACCENT_CONTRAST);
+        }
+
+        // Red comes from the palette, magenta and cyan are synthesized.
+        let hue = |color: &Rgb24| color.to_oklab().hue();
+        assert!(hue_distance(hue(&theme.ansi[1]), hue(&palette()[2])) < 5.0);
+        assert!(hue_distance(hue(&theme.ansi[5]), 328.0) < 10.0);
+        assert!(hue_distance(hue(&theme.ansi[6]), 195.0) < 10.0);
+
+        // Bright colors are lighter on dark themes.
+        assert!(theme.ansi[9].to_oklab().l > theme.ansi[1].to_oklab().l);
+
+        let light = Theme::from_palette(&palette(), true);
+        assert!(light.background.relative_luminance() > 0.8);
+        assert!(light.foreground.contrast_ratio(&light.background) >= TEXT_CONTRAST);
+        let ratio = |i: usize| light.ansi[i].contrast_ratio(&light.background);
+        for i in (0..7).chain(9..15) {
+            assert!(ratio(i) >= ACCENT_CONTRAST, "color{i}");
+        }
+        // White is a background color in light themes, and bright white
+        // only needs to be visible.
+        assert!(ratio(8) >= DIM_CONTRAST && ratio(15) >= DIM_CONTRAST);
+
+        // Grays and empty palettes still get colorful ANSI colors.
+        let theme = Theme::from_palette(&[], false);
+        assert!(theme.ansi[1].to_oklab().chroma() > MIN_CHROMA);
+    }
+
+    #[test]
+    fn theme_duplicates() {
+        // Both reds sort equal by HSV, so the duplicate is not adjacent.
+        let (red, near) = (Rgb24::new(200, 60, 50), Rgb24::new(200, 61, 50));
+        assert_eq!(red.cmp(&near), std::cmp::Ordering::Equal);
+
+        let green = Rgb24::new(60, 150, 70);
+        assert_eq!(
+            Theme::from_palette(&[red, near, red, green], false),
+            Theme::from_palette(&[red, near, green], false)
+        );
+    }
+
+    #[test]
+    fn theme_render() {
+        let theme = Theme::from_palette(&palette(), false);
+        let bg = theme.background.to_hex_string();
+
+        let alacritty = ThemeTarget::Alacritty.render(&theme, "x");
+        assert!(alacritty.contains(&format!("background = \"{bg}\"")));
+        assert!(alacritty.contains("[colors.bright]"));
+
+        let kitty = ThemeTarget::Kitty.render(&theme, "x");
+        assert_eq!(kitty.lines().count(), 20);
+        assert!(kitty.contains("color15 "));
+
+        let foot = ThemeTarget::Foot.render(&theme, "x");
+        assert!(foot.contains(&format!("background={}", &bg[1..])));
+
+        let wt = ThemeTarget::WindowsTerminal.render(&theme, "My \"theme\"");
+        assert!(wt.contains(r#""name": "My \"theme\"""#));
+        assert!(wt.contains("\"brightPurple\""));
+
+        let iterm = ThemeTarget::Iterm2.render(&theme, "x");
+        assert_eq!(iterm.matches("<key>Red Component</key>").count(), 20);
+
+        assert!(ThemeTarget::Wezterm
+            .render(&theme, "x")
+            .contains("brights = ["));
+        assert!(ThemeTarget::Xresources
+            .render(&theme, "x")
+            .contains("*.color8: "));
+    }
+}
@@ -0,0 +1,252 @@
+use std::collections::HashMap;
+
+use crate::color::{Lab, Rgb24};
+
+/// Weight of a pixel of full importance.
+pub const WEIGHT_SCALE: u64 = 64;
+
+/// Share of the full weight given to the least important pixels, so that
+/// the background still gets palette entries.
+const BASE_WEIGHT: f32 = 1.0 / 16.0;
+
+/// Standard deviation of the center weighting, relative to the image sides.
+const CENTER_SIGMA: f32 = 0.25;
+
+/// Built-in pixel weighting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum Weighting {
+    /// Gaussian falloff from the center of the image.
+    Center,
+    /// Contrast with the median color of the image.
+    Saliency,
+    /// Strength of the edges around each pixel, favoring detailed areas
+    /// over flat ones.
+    Edge,
+}
+
+impl Weighting {
+    /// Computes the weight of every pixel of an image.
+    ///
+    /// `pixels` are the `width * height` pixels of the image in row-major
+    /// order, including transparent ones.
+    ///
+    pub fn weights(&self, pixels: &[Rgb24], width: usize, height: usize) -> Vec<u64> {
+        match self {
+            Weighting::Center => center_weights(width, height),
+            Weighting::Saliency => saliency_weights(pixels, width, height),
+            Weighting::Edge => edge_weights(pixels, width, height),
+        }
+    }
+}
+
+/// Scales an importance in `[0, 1]` to a weight above the base weight.
+fn scale(importance: f32) -> u64 {
+    let weight = BASE_WEIGHT + (1.0 - BASE_WEIGHT) * importance.clamp(0.0, 1.0);
+    (weight * WEIGHT_SCALE as f32).round() as u64
+}
+
+/// Converts the pixels of an image to Lab, converting each color once.
+fn to_labs(pixels: &[Rgb24]) -> Vec<Lab> {
+    let mut cache = HashMap::new();
+    pixels
+        .iter()
+        .map(|color| *cache.entry(*color).or_insert_with(|| color.to_lab()))
+        .collect()
+}
+
+/// Scales importances by their maximum. Images where nothing stands out
+/// are weighted evenly.
+fn normalize(importance: Vec<f32>) -> Vec<u64> {
+    let max = importance.iter().copied().fold(0.0, f32::max);
+    if max <= 0.0 {
+        return vec![scale(1.0); importance.len()];
+    }
+
+    importance.into_iter().map(|i| scale(i / max)).collect()
+}
+
+/// Weighs pixels by a Gaussian centered on the image.
+pub fn center_weights(width: usize, height: usize) -> Vec<u64> {
+    let offset = |i: usize, n: usize| (i as f32 + 0.5) / n as f32 - 0.5;
+    let spread = 2.0 * CENTER_SIGMA * CENTER_SIGMA;
+
+    (0..height)
+        .flat_map(|y| (0..width).map(move |x| (x, y)))
+        .map(|(x, y)| {
+            let (dx, dy) = (offset(x, width), offset(y, height));
+            scale((-(dx * dx + dy * dy) / spread).exp())
+        })
+        .collect()
+}
+
+/// Weighs pixels by frequency-tuned saliency.
+///
+/// Each pixel is blurred with its neighbors, and its weight grows with the
+/// Delta E between the blurred color and the median color of the image.
+/// Subjects that stand out from their surroundings get the highest weights.
+/// Only contrast with the background counts, see [`edge_weights`] for
+/// edges and texture.
+///
+pub fn saliency_weights(pixels: &[Rgb24], width: usize, height: usize) -> Vec<u64> {
+    if width == 0 || height == 0 || pixels.len() < width * height {
+        return vec![];
+    }
+
+    let labs = to_labs(&pixels[..width * height]);
+
+    // The per-channel median stands for the background, unlike the mean it
+    // is not pulled toward small subjects.
+    let median = |channel: fn(&Lab) -> f32| {
+        let mut values: Vec<_> = labs.iter().map(channel).collect();
+        let mid = values.len() / 2;
+        *values.select_nth_unstable_by(mid, f32::total_cmp).1
+    };
+    let background = Lab {
+        l: median(|lab| lab.l),
+        a: median(|lab| lab.a),
+        b: median(|lab| lab.b),
+    };
+
+    // 3x3 box blur, clamped at the borders.
+    let blurred = |x: usize, y: usize| {
+        let mut sum = Lab::default();
+        let mut n = 0.0;
+
+        for ny in y.saturating_sub(1)..(y + 2).min(height) {
+            for nx in x.saturating_sub(1)..(x + 2).min(width) {
+                let lab = &labs[ny * width + nx];
+                sum.l += lab.l;
+                sum.a += lab.a;
+                sum.b += lab.b;
+                n += 1.0;
+            }
+        }
+
+        Lab {
+            l: sum.l / n,
+            a: sum.a / n,
+            b: sum.b / n,
+        }
+    };
+
+    let saliency = (0..height)
+        .flat_map(|y| (0..width).map(move |x| (x, y)))
+        .map(|(x, y)| blurred(x, y).delta_e(&background))
+        .collect();
+
+    normalize(saliency)
+}
+
+/// Weighs pixels by edge strength.
+///
+/// The weight of each pixel grows with the Sobel gradient magnitude of its
+/// Lab lightness, so outlines and texture get the highest weights and flat
+/// areas the lowest.
+///
+pub fn edge_weights(pixels: &[Rgb24], width: usize, height: usize) -> Vec<u64> {
+    if width == 0 || height == 0 || pixels.len() < width * height {
+        return vec![];
+    }
+
+    let lightness: Vec<f32> = to_labs(&pixels[..width * height])
+        .iter()
+        .map(|lab| lab.l)
+        .collect();
+
+    // Lightness of a neighbor, clamped at the borders.
+    let at = |x: usize, y: usize, dx: isize, dy: isize| {
+        let nx = x.saturating_add_signed(dx).min(width - 1);
+        let ny = y.saturating_add_signed(dy).min(height - 1);
+        lightness[ny * width + nx]
+    };
+
+    let gradient = (0..height)
+        .flat_map(|y| (0..width).map(move |x| (x, y)))
+        .map(|(x, y)| {
+            let gx = at(x, y, 1, -1) + 2.0 * at(x, y, 1, 0) + at(x, y, 1, 1)
+                - at(x, y, -1, -1)
+                - 2.0 * at(x, y, -1, 0)
+                - at(x, y, -1, 1);
+            let gy = at(x, y, -1, 1) + 2.0 * at(x, y, 0, 1) + at(x, y, 1, 1)
+                - at(x, y, -1, -1)
+                - 2.0 * at(x, y, 0, -1)
+                - at(x, y, 1, -1);
+            gx.hypot(gy)
+        })
+        .collect();
+
+    normalize(gradient)
+}
+
+/// Converts a grayscale weight map to weights.
+///
+/// White pixels get the full [`WEIGHT_SCALE`] and black pixels are
+/// ignored.
+///
+pub fn map_weights(map: &[u8]) -> Vec<u64> {
+    map.iter()
+        .map(|&v| (v as u64 * WEIGHT_SCALE + 127) / 255)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn weight_center() {
+        let weights = center_weights(5, 3);
+        assert_eq!(weights.len(), 15);
+
+        let center = weights[7];
+        assert!(weights.iter().all(|&w| w <= center && w > 0));
+        assert!(weights[0] < weights[1] && weights[1] < weights[2]);
+        assert_eq!(weights[0], weights[14]);
+    }
+
+    #[test]
+    fn weight_saliency() {
+        let gray = Rgb24::new(128, 128, 128);
+        let red = Rgb24::new(220, 0, 0);
+
+        // A red dot on a gray wall.
+        let mut pixels = vec![gray; 49];
+        pixels[24] = red;
+
+        let weights = saliency_weights(&pixels, 7, 7);
+        assert_eq!(weights[24], WEIGHT_SCALE);
+        assert_eq!(weights[0], scale(0.0));
+        assert!(weights[23] > weights[0]);
+
+        // Uniform images are weighted evenly.
+        assert_eq!(saliency_weights(&[gray; 4], 2, 2), vec![WEIGHT_SCALE; 4]);
+        assert!(saliency_weights(&[], 0, 0).is_empty());
+    }
+
+    #[test]
+    fn weight_edge() {
+        let black = Rgb24::new(0, 0, 0);
+        let white = Rgb24::new(255, 255, 255);
+
+        // Left half black, right half white.
+        let pixels: Vec<_> = (0..36)
+            .map(|i| if i % 6 < 3 { black } else { white })
+            .collect();
+
+        let weights = edge_weights(&pixels, 6, 6);
+        assert_eq!(weights[2], WEIGHT_SCALE);
+        assert_eq!(weights[3], WEIGHT_SCALE);
+        assert_eq!(weights[0], scale(0.0));
+        assert_eq!(weights[5], scale(0.0));
+
+        // Flat images are weighted evenly.
+        assert_eq!(edge_weights(&[black; 4], 2, 2), vec![WEIGHT_SCALE; 4]);
+        assert!(edge_weights(&[], 0, 0).is_empty());
+    }
+
+    #[test]
+    fn weight_map() {
+        assert_eq!(map_weights(&[0, 128, 255]), vec![0, 32, WEIGHT_SCALE]);
+    }
+}